@@ -0,0 +1,206 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::{glob, read, shell};
+use std::convert::TryInto;
+
+/// Files holding `key=value` properties, in the order Android loads them. Read-only properties
+/// can't be overridden once set, so the first file that has a property wins.
+const PROP_FILES: &[&str] = &[
+    "/system/etc/prop.default",
+    "/default.prop",
+    "/system/build.prop",
+    "/vendor/default.prop",
+    "/vendor/build.prop",
+    "/odm/etc/build.prop",
+    "/product/etc/build.prop",
+    "/product/build.prop",
+    "/system_ext/etc/build.prop",
+];
+
+/// The directory holding the shared memory property areas, one per SELinux context.
+const PROP_AREA_DIR: &str = "/dev/__properties__";
+
+// Layout of a property area, from bionic's `system_properties/prop_area.h`.
+const PROP_AREA_MAGIC: u32 = 0x504f_5250;
+const PROP_AREA_HEADER_SIZE: usize = 128;
+const PROP_VALUE_MAX: usize = 92;
+const PROP_LONG_FLAG: u32 = 1 << 16;
+const PROP_LONG_OFFSET: usize = 56;
+
+/// Info about an Android system, from its build properties.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AndroidInfo {
+    /// `ro.build.version.release`, e.g. "14".
+    pub release: Option<String>,
+    /// `ro.build.version.sdk`, e.g. 34.
+    pub sdk: Option<u32>,
+    /// `ro.build.flavor`, e.g. "husky-user".
+    pub flavor: Option<String>,
+    /// `ro.product.manufacturer`
+    pub manufacturer: Option<String>,
+    /// `ro.product.model`
+    pub model: Option<String>,
+    /// `ro.product.device`
+    pub device: Option<String>,
+    /// `ro.build.version.security_patch`, e.g. "2024-01-05".
+    pub security_patch: Option<String>,
+}
+
+/// The property sources that could be read, so several lookups only read them once.
+struct Properties {
+    areas: Vec<Vec<u8>>,
+    files: Vec<String>,
+}
+
+impl Properties {
+    fn load(probe: &Probe) -> Properties {
+        let areas = glob(probe.path(PROP_AREA_DIR).join("*"))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|path| std::fs::read(path).ok())
+            .filter(|area| u32_at(area, 8) == Some(PROP_AREA_MAGIC))
+            .collect();
+        let files = PROP_FILES
+            .iter()
+            .filter_map(|file| read(probe.path(file)).ok())
+            .collect();
+        Properties { areas, files }
+    }
+
+    /// Looks `name` up in the areas and files, then asks `getprop` for what they don't have.
+    /// Since Android 10 properties like `ro.product.model` are derived at boot from
+    /// `ro.product.{system,vendor,odm}.*`, so they're often only in areas that can't be read.
+    fn get(&self, probe: &Probe, name: &str) -> Result<String> {
+        let found = self
+            .areas
+            .iter()
+            .find_map(|area| area_lookup(area.get(PROP_AREA_HEADER_SIZE..)?, name))
+            .or_else(|| self.files.iter().find_map(|file| file_lookup(file, name)));
+        if let Some(value) = found {
+            return Ok(value);
+        }
+        let not_found = || Error::NotFound(format!("android property {}", name));
+        match probe.run("getprop", &[name]) {
+            Ok(output) => {
+                let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                if value.is_empty() {
+                    Err(not_found())
+                } else {
+                    Ok(value)
+                }
+            }
+            // With nothing readable, why `getprop` couldn't be run is the better error.
+            Err(e) if self.areas.is_empty() && self.files.is_empty() => Err(e),
+            Err(_) => Err(not_found()),
+        }
+    }
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+    Some(u32::from_le_bytes(bytes))
+}
+
+/// Reads a NUL terminated string of at most `max` bytes.
+fn c_str_at(data: &[u8], offset: usize, max: usize) -> Option<String> {
+    let bytes = data.get(offset..)?;
+    let bytes = &bytes[..max.min(bytes.len())];
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    Some(String::from_utf8_lossy(&bytes[..end]).into_owned())
+}
+
+/// Searches the binary tree of siblings starting at `node` for `name`. Siblings are ordered by
+/// name length first, then by the name's bytes.
+///
+/// A node takes at least 20 bytes, so a search taking more steps than fit in `data` has hit a
+/// cycle in a corrupt area.
+fn find_sibling(data: &[u8], mut node: usize, name: &[u8]) -> Option<usize> {
+    for _ in 0..data.len() / 20 {
+        let namelen = u32_at(data, node)? as usize;
+        let node_name = data.get(node + 20..node + 20 + namelen)?;
+        let next = match name.len().cmp(&namelen).then_with(|| name.cmp(node_name)) {
+            std::cmp::Ordering::Equal => return Some(node),
+            std::cmp::Ordering::Less => u32_at(data, node + 8)?,
+            std::cmp::Ordering::Greater => u32_at(data, node + 12)?,
+        };
+        if next == 0 || next as usize >= data.len() {
+            return None;
+        }
+        node = next as usize;
+    }
+    None
+}
+
+/// Looks up a property in the trie of a property area, where each level is one `.` separated
+/// segment of the name.
+fn area_lookup(data: &[u8], name: &str) -> Option<String> {
+    let mut node = 0;
+    for segment in name.split('.') {
+        let children = u32_at(data, node + 16)?;
+        if children == 0 {
+            return None;
+        }
+        node = find_sibling(data, children as usize, segment.as_bytes())?;
+    }
+    let info = u32_at(data, node + 4)? as usize;
+    if info == 0 {
+        return None;
+    }
+    let serial = u32_at(data, info)?;
+    let value = info + 4;
+    if serial & PROP_LONG_FLAG != 0 {
+        let offset = u32_at(data, value + PROP_LONG_OFFSET)? as usize;
+        c_str_at(data, info + offset, usize::MAX)
+    } else {
+        c_str_at(data, value, PROP_VALUE_MAX)
+    }
+}
+
+fn file_lookup(contents: &str, name: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let (key, value) = line.trim().split_once('=')?;
+        if key.trim() == name {
+            Some(value.trim().to_string())
+        } else {
+            None
+        }
+    })
+}
+
+pub fn property(probe: &Probe, name: &str) -> Result<String> {
+    Properties::load(probe).get(probe, name)
+}
+
+pub fn info(probe: &Probe) -> AndroidInfo {
+    let properties = Properties::load(probe);
+    let get = |name: &str| properties.get(probe, name).ok();
+    AndroidInfo {
+        release: get("ro.build.version.release"),
+        sdk: get("ro.build.version.sdk").and_then(|sdk| sdk.parse().ok()),
+        flavor: get("ro.build.flavor"),
+        manufacturer: get("ro.product.manufacturer"),
+        model: get("ro.product.model"),
+        device: get("ro.product.device"),
+        security_patch: get("ro.build.version.security_patch"),
+    }
+}
+
+pub fn device(probe: &Probe) -> Result<String> {
+    let properties = Properties::load(probe);
+    let product = properties.get(probe, "ro.product.name")?;
+    let model = properties.get(probe, "ro.product.model")?;
+    let device = properties.get(probe, "ro.product.device")?;
+    Ok(format!("{} {} ({})", product, model, device))
+}
+
+pub fn distro(probe: &Probe) -> Result<String> {
+    let properties = Properties::load(probe);
+    let release = properties.get(probe, "ro.build.version.release")?;
+    let flavor = properties.get(probe, "ro.build.flavor")?;
+    Ok(format!("Android {} ({})", release, flavor))
+}
+
+pub fn user() -> Result<String> {
+    shell("whoami")
+}
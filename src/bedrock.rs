@@ -0,0 +1,92 @@
+use crate::distro::OsRelease;
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::read;
+use std::fmt;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+
+/// A stratum of a Bedrock Linux system, see `brl status`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stratum {
+    /// The stratum's name, e.g. "arch".
+    pub name: String,
+    /// `NAME` from the stratum's own os-release, e.g. "Arch Linux".
+    pub distro: Option<String>,
+    pub enabled: bool,
+    /// Whether the init system (PID 1) comes from this stratum, from the `init` alias or, without
+    /// one, by comparing with `/proc/1/root`, which only works as root.
+    pub provides_init: bool,
+    /// Whether the shell nixinfo was started from comes from this stratum.
+    pub provides_shell: bool,
+}
+
+impl fmt::Display for Stratum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Returns the device and inode of a directory, to tell whether two paths are the same directory.
+fn identity(path: &Path) -> Option<(u64, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+fn stratum_distro(path: &Path) -> Option<String> {
+    ["etc/os-release", "usr/lib/os-release"].iter().find_map(|file| {
+        let file = path.join(file);
+        let contents = read(&file).ok()?;
+        Some(OsRelease::parse(&contents, &file.display().to_string()).ok()?.name)
+    })
+}
+
+pub fn strata(probe: &Probe) -> Result<Vec<Stratum>> {
+    let strata_dir = probe.path("/bedrock/strata");
+    let entries = std::fs::read_dir(&strata_dir).map_err(|e| Error::io(e, strata_dir.display()))?;
+
+    // A process' root is the root of the stratum it was started from.
+    let root_of = |pid: &str| identity(&probe.path(format!("/proc/{}/root", pid)));
+    // Only root may look at `/proc/1/root`, so prefer the `init` alias Bedrock keeps pointing at
+    // the stratum that provides init.
+    let init_alias = std::fs::read_link(strata_dir.join("init"))
+        .ok()
+        .and_then(|target| Some(target.file_name()?.to_string_lossy().into_owned()));
+    let init_root = if init_alias.is_none() {
+        root_of("1")
+    } else {
+        None
+    };
+    let shell_root = crate::terminal::ppid(probe, &std::process::id().to_string())
+        .ok()
+        .and_then(|ppid| root_of(&ppid));
+
+    let mut strata = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| Error::io(e, strata_dir.display()))?;
+        let path = entry.path();
+        // Aliases such as `init` or `local` are symlinks to the real strata.
+        let is_alias = entry.file_type().map(|t| t.is_symlink()).unwrap_or(true);
+        if is_alias || !path.is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let root = identity(&path);
+        strata.push(Stratum {
+            distro: stratum_distro(&path),
+            enabled: probe
+                .path("/bedrock/run/enabled_strata")
+                .join(&name)
+                .exists(),
+            provides_init: match &init_alias {
+                Some(init) => *init == name,
+                None => root.is_some() && root == init_root,
+            },
+            provides_shell: root.is_some() && root == shell_root,
+            name,
+        });
+    }
+    strata.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(strata)
+}
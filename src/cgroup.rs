@@ -0,0 +1,177 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::{cpu_list, read};
+use std::path::{Path, PathBuf};
+
+/// v1 reports "no limit" as a huge page-aligned number rather than `max`.
+const V1_UNLIMITED: u64 = 1 << 62;
+
+/// Which cgroup hierarchy the process lives in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CgroupVersion {
+    /// One hierarchy per controller, under `/sys/fs/cgroup/<controller>`.
+    V1,
+    /// The unified hierarchy, mounted at `/sys/fs/cgroup`.
+    V2,
+}
+
+/// The resource limits of the current process's cgroup, as set by e.g. Docker, Kubernetes or
+/// systemd. `None` means there is no limit, or the controller isn't available.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cgroup {
+    pub version: CgroupVersion,
+    /// The cgroup of the process, from `/proc/self/cgroup`, e.g. "/system.slice/docker-1234.scope".
+    pub path: String,
+    /// The lowest memory limit of the cgroup and its parents, in bytes.
+    pub memory_max: Option<u64>,
+    /// Memory currently charged to the cgroup, in bytes.
+    pub memory_current: Option<u64>,
+    /// The CPU quota as a number of CPUs, e.g. 1.5 for `cpu.max` set to "150000 100000".
+    pub cpu_max: Option<f64>,
+    /// The CPUs the cgroup may run on.
+    pub cpuset: Option<Vec<u32>>,
+}
+
+/// Finds the cgroup path of `controller` in `/proc/self/cgroup`, or the v2 path for `None`.
+fn cgroup_path<'a>(contents: &'a str, controller: Option<&str>) -> Option<&'a str> {
+    contents.lines().find_map(|line| {
+        let mut fields = line.splitn(3, ':');
+        let _id = fields.next()?;
+        let controllers = fields.next()?;
+        let path = fields.next()?;
+        let matches = match controller {
+            None => controllers.is_empty(),
+            Some(controller) => controllers.split(',').any(|c| c == controller),
+        };
+        if matches {
+            Some(path)
+        } else {
+            None
+        }
+    })
+}
+
+/// The directories from the process's cgroup up to the root of the hierarchy mounted at `mount`.
+///
+/// With a cgroup namespace the process sees itself at `/`, and without one the host path may not
+/// exist in a container's mount, in which case the mount's root is the process's cgroup.
+fn directories(mount: &Path, path: &str) -> Vec<PathBuf> {
+    let mut dir = mount.join(path.trim_start_matches('/'));
+    if !dir.is_dir() {
+        dir = mount.to_path_buf();
+    }
+    let mut dirs = vec![dir.clone()];
+    while dir != mount {
+        if !dir.pop() || !dir.starts_with(mount) {
+            break;
+        }
+        dirs.push(dir.clone());
+    }
+    dirs
+}
+
+fn value(dir: &Path, file: &str) -> Option<String> {
+    read(dir.join(file))
+        .ok()
+        .map(|value| value.trim().to_string())
+}
+
+fn cpuset(dir: &Path, file: &str) -> Option<Vec<u32>> {
+    let path = dir.join(file);
+    let list = value(dir, file).filter(|list| !list.is_empty())?;
+    cpu_list(&list, &path.display().to_string()).ok()
+}
+
+/// Parses v2's `cpu.max`, e.g. "max 100000" or "150000 100000".
+fn cpu_max(value: &str) -> Option<f64> {
+    let (quota, period) = value.split_once(' ')?;
+    let quota: f64 = quota.parse().ok()?;
+    let period: f64 = period.trim().parse().ok()?;
+    Some(quota / period)
+}
+
+fn lowest<I: Iterator<Item = f64>>(limits: I) -> Option<f64> {
+    limits.fold(None, |lowest, limit| {
+        Some(lowest.map_or(limit, |lowest: f64| lowest.min(limit)))
+    })
+}
+
+fn v2(probe: &Probe, contents: &str) -> Cgroup {
+    let path = cgroup_path(contents, None).unwrap_or("/").to_string();
+    let dirs = directories(&probe.path("/sys/fs/cgroup"), &path);
+    Cgroup {
+        version: CgroupVersion::V2,
+        memory_max: dirs
+            .iter()
+            .filter_map(|dir| value(dir, "memory.max")?.parse::<u64>().ok())
+            .min(),
+        memory_current: value(&dirs[0], "memory.current").and_then(|current| current.parse().ok()),
+        cpu_max: lowest(
+            dirs.iter()
+                .filter_map(|dir| cpu_max(&value(dir, "cpu.max")?)),
+        ),
+        cpuset: cpuset(&dirs[0], "cpuset.cpus.effective"),
+        path,
+    }
+}
+
+fn v1(probe: &Probe, contents: &str) -> Cgroup {
+    // Controllers are often co-mounted, e.g. at `/sys/fs/cgroup/cpu,cpuacct` with `cpu` as a
+    // symlink to it.
+    let dirs = |controller: &str| {
+        let path = cgroup_path(contents, Some(controller))?;
+        let mount = probe.path("/sys/fs/cgroup").join(controller);
+        Some((path.to_string(), directories(&mount, path)))
+    };
+    let memory = dirs("memory");
+    let cpu = dirs("cpu");
+    let cpuset_dirs = dirs("cpuset");
+
+    let memory_max = memory.as_ref().and_then(|(_, dirs)| {
+        dirs.iter()
+            .filter_map(|dir| value(dir, "memory.limit_in_bytes")?.parse::<u64>().ok())
+            .filter(|limit| *limit < V1_UNLIMITED)
+            .min()
+    });
+    let cpu_max = cpu.as_ref().and_then(|(_, dirs)| {
+        lowest(dirs.iter().filter_map(|dir| {
+            let quota: f64 = value(dir, "cpu.cfs_quota_us")?.parse().ok()?;
+            let period: f64 = value(dir, "cpu.cfs_period_us")?.parse().ok()?;
+            // A quota of -1 means no limit.
+            if quota > 0.0 && period > 0.0 {
+                Some(quota / period)
+            } else {
+                None
+            }
+        }))
+    });
+    Cgroup {
+        version: CgroupVersion::V1,
+        path: memory
+            .as_ref()
+            .or(cpu.as_ref())
+            .map(|(path, _)| path.clone())
+            .unwrap_or_else(|| "/".to_string()),
+        memory_max,
+        memory_current: memory
+            .as_ref()
+            .and_then(|(_, dirs)| value(&dirs[0], "memory.usage_in_bytes")?.parse().ok()),
+        cpu_max,
+        cpuset: cpuset_dirs.as_ref().and_then(|(_, dirs)| {
+            cpuset(&dirs[0], "cpuset.effective_cpus").or_else(|| cpuset(&dirs[0], "cpuset.cpus"))
+        }),
+    }
+}
+
+pub fn cgroup(probe: &Probe) -> Result<Cgroup> {
+    let contents = read(probe.path("/proc/self/cgroup"))?;
+    if probe.path("/sys/fs/cgroup/cgroup.controllers").exists() {
+        Ok(v2(probe, &contents))
+    } else if probe.path("/sys/fs/cgroup").is_dir() {
+        Ok(v1(probe, &contents))
+    } else {
+        Err(Error::NotFound("cgroup filesystem".to_string()))
+    }
+}
@@ -1,11 +1,269 @@
-pub fn get(file: std::fs::File, x: usize) -> String {
-    let line = crate::shared_functions::line(file, x);
-    let line_vec: Vec<&str> = line.split(':').collect();
-    line_vec[1].to_string()
+use crate::error::{Error, Result};
+use std::collections::HashSet;
+
+/// What `/proc/cpuinfo` says about the CPU.
+///
+/// The layout of the file differs per architecture, so most fields are only filled in where the
+/// kernel reports them. On ARM the vendor and model name are decoded from `CPU implementer` and
+/// `CPU part`, on RISC-V they come from `uarch`, and on POWER from the `cpu` line.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuInfo {
+    /// e.g. "GenuineIntel", "AuthenticAMD", "ARM" or "Qualcomm".
+    pub vendor: Option<String>,
+    /// e.g. "AMD Ryzen 7 5800X 8-Core Processor" or "ARM Cortex-A72".
+    pub model_name: Option<String>,
+    /// `cpu family` on x86, `CPU architecture` on ARM.
+    pub family: Option<u32>,
+    /// `model` on x86, `CPU part` on ARM.
+    pub model: Option<u32>,
+    /// `stepping` on x86, `CPU revision` on ARM.
+    pub stepping: Option<u32>,
+    pub microcode: Option<String>,
+    /// `flags` on x86, `Features` on ARM.
+    pub flags: Vec<String>,
+    /// Physical cores across all sockets.
+    pub cores: u32,
+    /// Logical CPUs, i.e. processor entries.
+    pub threads: u32,
+    pub sockets: u32,
+    /// Size of the last level cache in bytes, on x86.
+    pub cache_size: Option<u64>,
+    pub bogomips: Option<f64>,
+    /// The SoC reported by `Hardware` on ARM, e.g. "BCM2835".
+    pub hardware: Option<String>,
+    /// The ISA string on RISC-V, e.g. "rv64imafdc".
+    pub isa: Option<String>,
+    /// The microarchitecture on RISC-V, e.g. "sifive,u74-mc".
+    pub uarch: Option<String>,
+}
+
+/// ARM implementer IDs, from `CPU implementer`.
+const ARM_IMPLEMENTERS: &[(u32, &str)] = &[
+    (0x41, "ARM"),
+    (0x42, "Broadcom"),
+    (0x43, "Cavium"),
+    (0x44, "DEC"),
+    (0x46, "Fujitsu"),
+    (0x48, "HiSilicon"),
+    (0x49, "Infineon"),
+    (0x4d, "Motorola/Freescale"),
+    (0x4e, "NVIDIA"),
+    (0x50, "APM"),
+    (0x51, "Qualcomm"),
+    (0x53, "Samsung"),
+    (0x56, "Marvell"),
+    (0x61, "Apple"),
+    (0x66, "Faraday"),
+    (0x69, "Intel"),
+    (0x6d, "Microsoft"),
+    (0x70, "Phytium"),
+    (0xc0, "Ampere"),
+];
+
+/// ARM core names by implementer and `CPU part`.
+const ARM_PARTS: &[(u32, u32, &str)] = &[
+    (0x41, 0xb76, "ARM1176"),
+    (0x41, 0xc07, "Cortex-A7"),
+    (0x41, 0xc08, "Cortex-A8"),
+    (0x41, 0xc09, "Cortex-A9"),
+    (0x41, 0xc0d, "Cortex-A12"),
+    (0x41, 0xc0e, "Cortex-A17"),
+    (0x41, 0xc0f, "Cortex-A15"),
+    (0x41, 0xd01, "Cortex-A32"),
+    (0x41, 0xd02, "Cortex-A34"),
+    (0x41, 0xd03, "Cortex-A53"),
+    (0x41, 0xd04, "Cortex-A35"),
+    (0x41, 0xd05, "Cortex-A55"),
+    (0x41, 0xd06, "Cortex-A65"),
+    (0x41, 0xd07, "Cortex-A57"),
+    (0x41, 0xd08, "Cortex-A72"),
+    (0x41, 0xd09, "Cortex-A73"),
+    (0x41, 0xd0a, "Cortex-A75"),
+    (0x41, 0xd0b, "Cortex-A76"),
+    (0x41, 0xd0c, "Neoverse-N1"),
+    (0x41, 0xd0d, "Cortex-A77"),
+    (0x41, 0xd40, "Neoverse-V1"),
+    (0x41, 0xd41, "Cortex-A78"),
+    (0x41, 0xd44, "Cortex-X1"),
+    (0x41, 0xd46, "Cortex-A510"),
+    (0x41, 0xd47, "Cortex-A710"),
+    (0x41, 0xd48, "Cortex-X2"),
+    (0x41, 0xd49, "Neoverse-N2"),
+    (0x41, 0xd4b, "Cortex-A78C"),
+    (0x41, 0xd4d, "Cortex-A715"),
+    (0x41, 0xd4e, "Cortex-X3"),
+    (0x41, 0xd4f, "Neoverse-V2"),
+    (0x41, 0xd80, "Cortex-A520"),
+    (0x41, 0xd81, "Cortex-A720"),
+    (0x41, 0xd82, "Cortex-X4"),
+    (0x42, 0x516, "ThunderX2"),
+    (0x43, 0x0a1, "ThunderX"),
+    (0x43, 0x0af, "ThunderX2"),
+    (0x48, 0xd01, "Kunpeng-920"),
+    (0x4e, 0x004, "Carmel"),
+    (0x50, 0x000, "X-Gene"),
+    (0x51, 0x800, "Kryo 2XX Gold"),
+    (0x51, 0x801, "Kryo 2XX Silver"),
+    (0x51, 0x802, "Kryo 3XX Gold"),
+    (0x51, 0x803, "Kryo 3XX Silver"),
+    (0x51, 0x804, "Kryo 4XX Gold"),
+    (0x51, 0x805, "Kryo 4XX Silver"),
+    (0x51, 0xc00, "Falkor"),
+    (0x53, 0x001, "Exynos-M1"),
+    (0x61, 0x022, "Icestorm"),
+    (0x61, 0x023, "Firestorm"),
+    (0xc0, 0xac3, "Ampere-1"),
+];
+
+/// Parses a number written in decimal or, with a `0x` prefix, in hex.
+fn number(value: &str) -> Option<u32> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+/// Parses a size such as "8192 KB".
+fn size(value: &str) -> Option<u64> {
+    let mut parts = value.split_whitespace();
+    let amount: u64 = parts.next()?.parse().ok()?;
+    let multiplier = match parts.next().map(|unit| unit.to_uppercase()).as_deref() {
+        Some("KB") | Some("K") => 1024,
+        Some("MB") | Some("M") => 1024 * 1024,
+        _ => 1,
+    };
+    Some(amount * multiplier)
+}
+
+/// Splits cpuinfo into blocks of `key: value` pairs, separated by blank lines.
+fn blocks(contents: &str) -> Vec<Vec<(&str, &str)>> {
+    let mut blocks = vec![Vec::new()];
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            blocks.push(Vec::new());
+        } else if let Some((key, value)) = line.split_once(':') {
+            if let Some(block) = blocks.last_mut() {
+                block.push((key.trim(), value.trim()));
+            }
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+impl CpuInfo {
+    /// Parses the contents of `/proc/cpuinfo`, `path` is only used in errors.
+    pub fn parse(contents: &str, path: &str) -> Result<CpuInfo> {
+        let blocks = blocks(contents);
+        let (processors, global): (Vec<_>, Vec<_>) = blocks
+            .iter()
+            .partition(|block| block.iter().any(|(key, _)| *key == "processor"));
+        if processors.is_empty() {
+            return Err(Error::parse(path, None, "no processor entries"));
+        }
+        // The first processor's values describe the CPU, other blocks hold system wide values
+        // such as `Hardware` on ARM or `model` on POWER.
+        let get = |key: &str| {
+            processors[0]
+                .iter()
+                .chain(global.iter().flat_map(|block| block.iter()))
+                .find(|(k, _)| *k == key)
+                .map(|(_, value)| value.to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let implementer = get("CPU implementer").as_deref().and_then(number);
+        let part = get("CPU part").as_deref().and_then(number);
+        let arm_vendor = implementer.and_then(|implementer| {
+            ARM_IMPLEMENTERS
+                .iter()
+                .find(|(id, _)| *id == implementer)
+                .map(|(_, name)| name.to_string())
+        });
+        let arm_core = ARM_PARTS
+            .iter()
+            .find(|(id, id_part, _)| Some(*id) == implementer && Some(*id_part) == part)
+            .map(|(_, _, name)| name.to_string());
+        let uarch = get("uarch");
+        // POWER's `cpu` line looks like "POWER9 (raw), altivec supported".
+        let power_cpu =
+            get("cpu").map(|cpu| cpu.split(',').next().unwrap_or("").trim().to_string());
+
+        let vendor = get("vendor_id")
+            .or(arm_vendor.clone())
+            .or_else(|| {
+                uarch
+                    .as_deref()
+                    .and_then(|uarch| uarch.split(',').next())
+                    .map(String::from)
+            })
+            .or_else(|| power_cpu.as_ref().map(|_| "IBM".to_string()));
+        let model_name = match (&arm_vendor, &arm_core) {
+            (Some(vendor), Some(core)) => Some(format!("{} {}", vendor, core)),
+            _ => None,
+        }
+        .or_else(|| get("model name"))
+        .or_else(|| get("Processor"))
+        .or_else(|| uarch.clone())
+        .or(power_cpu)
+        .or_else(|| get("isa"))
+        .or_else(|| get("Hardware"));
+
+        let flags = get("flags")
+            .or_else(|| get("Features"))
+            .map(|flags| flags.split_whitespace().map(String::from).collect())
+            .unwrap_or_default();
+
+        let threads = processors.len() as u32;
+        let value = |block: &Vec<(&str, &str)>, key: &str| {
+            block
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, value)| value.to_string())
+        };
+        let sockets: HashSet<_> = processors
+            .iter()
+            .filter_map(|block| value(block, "physical id"))
+            .collect();
+        let cores: HashSet<_> = processors
+            .iter()
+            .filter_map(|block| Some((value(block, "physical id")?, value(block, "core id")?)))
+            .collect();
+
+        Ok(CpuInfo {
+            vendor,
+            model_name,
+            family: get("cpu family")
+                .or_else(|| get("CPU architecture"))
+                .as_deref()
+                .and_then(number),
+            model: get("model").as_deref().and_then(number).or(part),
+            stepping: get("stepping")
+                .or_else(|| get("CPU revision"))
+                .as_deref()
+                .and_then(number),
+            microcode: get("microcode"),
+            flags,
+            // Without core ids, assume there is no SMT.
+            cores: if cores.is_empty() { threads } else { cores.len() as u32 },
+            threads,
+            sockets: sockets.len().max(1) as u32,
+            cache_size: get("cache size").as_deref().and_then(size),
+            bogomips: get("bogomips")
+                .or_else(|| get("BogoMIPS"))
+                .and_then(|bogomips| bogomips.parse().ok()),
+            hardware: get("Hardware"),
+            isa: get("isa"),
+            uarch,
+        })
+    }
 }
 
 pub fn format(info: String) -> String {
     info.replace("(TM)", "")
         .replace("(R)", "")
-        .replace("     ", " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
 }
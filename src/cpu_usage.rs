@@ -0,0 +1,165 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::read;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// How CPU time was spent over an interval, in percent.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsageBreakdown {
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    /// Time a hypervisor gave to other guests while this one wanted to run.
+    pub steal: f64,
+}
+
+impl UsageBreakdown {
+    /// The percentage of time spent doing anything but idling or waiting on IO.
+    pub fn busy(&self) -> f64 {
+        (100.0 - self.idle - self.iowait).max(0.0)
+    }
+}
+
+/// CPU usage over an interval, for all CPUs together and for each online CPU.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuUsage {
+    pub total: UsageBreakdown,
+    /// Keyed by the N in `cpuN`.
+    pub cores: BTreeMap<u32, UsageBreakdown>,
+}
+
+/// The tick counters of one `cpu` line of `/proc/stat`, in the order the kernel prints them.
+#[derive(Clone, Copy, Debug, Default)]
+struct Ticks([u64; 8]);
+
+impl Ticks {
+    fn since(&self, earlier: &Ticks) -> UsageBreakdown {
+        let mut delta = [0; 8];
+        for (i, delta) in delta.iter_mut().enumerate() {
+            // Counters of a CPU that went offline and came back can start over.
+            *delta = self.0[i].saturating_sub(earlier.0[i]);
+        }
+        let total: u64 = delta.iter().sum();
+        // No ticks passed, so nothing was busy.
+        if total == 0 {
+            return UsageBreakdown {
+                idle: 100.0,
+                ..UsageBreakdown::default()
+            };
+        }
+        let percent = |i: usize| delta[i] as f64 * 100.0 / total as f64;
+        UsageBreakdown {
+            user: percent(0),
+            nice: percent(1),
+            system: percent(2),
+            idle: percent(3),
+            iowait: percent(4),
+            irq: percent(5),
+            softirq: percent(6),
+            steal: percent(7),
+        }
+    }
+}
+
+/// One reading of `/proc/stat`.
+#[derive(Clone, Debug, Default)]
+struct Sample {
+    total: Ticks,
+    cores: BTreeMap<u32, Ticks>,
+}
+
+impl Sample {
+    fn read(probe: &Probe) -> Result<Sample> {
+        let path = probe.path("/proc/stat");
+        let contents = read(&path)?;
+        let mut sample = Sample::default();
+        for (number, line) in contents.lines().enumerate() {
+            let mut fields = line.split_whitespace();
+            let name = match fields.next() {
+                Some(name) if name.starts_with("cpu") => name,
+                _ => continue,
+            };
+            // Older kernels have fewer columns, those are left at zero.
+            let mut ticks = Ticks::default();
+            for (tick, field) in ticks.0.iter_mut().zip(fields) {
+                *tick = field.parse().map_err(|_| {
+                    Error::parse(path.display(), Some(number + 1), "invalid tick count")
+                })?;
+            }
+            match name.trim_start_matches("cpu") {
+                "" => sample.total = ticks,
+                cpu => {
+                    let cpu = cpu.parse().map_err(|_| {
+                        Error::parse(path.display(), Some(number + 1), "invalid CPU number")
+                    })?;
+                    sample.cores.insert(cpu, ticks);
+                }
+            }
+        }
+        Ok(sample)
+    }
+
+    fn since(&self, earlier: &Sample) -> CpuUsage {
+        CpuUsage {
+            total: self.total.since(&earlier.total),
+            cores: self
+                .cores
+                .iter()
+                .map(|(cpu, ticks)| {
+                    let earlier = earlier.cores.get(cpu).copied().unwrap_or_default();
+                    (*cpu, ticks.since(&earlier))
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn usage(probe: &Probe, interval: Duration) -> Result<CpuUsage> {
+    let before = Sample::read(probe)?;
+    std::thread::sleep(interval);
+    Ok(Sample::read(probe)?.since(&before))
+}
+
+/// Measures CPU usage between calls to [`CpuSampler::poll`] without sleeping, for status bars
+/// and other programs that already run on a timer.
+#[derive(Clone, Debug)]
+pub struct CpuSampler {
+    probe: Probe,
+    previous: Sample,
+}
+
+impl CpuSampler {
+    /// A sampler for the running system.
+    pub fn new() -> CpuSampler {
+        CpuSampler::with_probe(Probe::new())
+    }
+
+    /// A sampler reading `/proc/stat` through `probe`.
+    pub fn with_probe(probe: Probe) -> CpuSampler {
+        CpuSampler {
+            probe,
+            previous: Sample::default(),
+        }
+    }
+
+    /// The usage since the previous poll. The first poll returns the average since boot.
+    pub fn poll(&mut self) -> Result<CpuUsage> {
+        let sample = Sample::read(&self.probe)?;
+        let usage = sample.since(&self.previous);
+        self.previous = sample;
+        Ok(usage)
+    }
+}
+
+impl Default for CpuSampler {
+    fn default() -> CpuSampler {
+        CpuSampler::new()
+    }
+}
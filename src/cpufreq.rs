@@ -0,0 +1,91 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::{glob, read};
+use std::fmt;
+use std::path::Path;
+
+/// A clock frequency in kHz, the unit cpufreq reports in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frequency(pub u64);
+
+impl fmt::Display for Frequency {
+    /// Formats as e.g. "4.70 GHz", or "800 MHz" below 1 GHz.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 >= 1_000_000 {
+            write!(f, "{:.2} GHz", self.0 as f64 / 1_000_000.0)
+        } else {
+            write!(f, "{} MHz", self.0 / 1000)
+        }
+    }
+}
+
+/// The frequency scaling state of one logical CPU, from
+/// `/sys/devices/system/cpu/cpuN/cpufreq/`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuFreq {
+    /// The N in `cpuN`.
+    pub cpu: u32,
+    pub current: Option<Frequency>,
+    /// The lowest frequency the hardware supports.
+    pub min: Option<Frequency>,
+    /// The highest frequency the hardware supports, including boost.
+    pub max: Option<Frequency>,
+    /// e.g. "powersave", "performance" or "schedutil".
+    pub governor: Option<String>,
+    /// e.g. "intel_pstate", "amd-pstate-epp" or "acpi-cpufreq".
+    pub driver: Option<String>,
+}
+
+/// Reads the first of `files` in `dir` that exists.
+fn first(dir: &Path, files: &[&str]) -> Option<String> {
+    files
+        .iter()
+        .find_map(|file| read(dir.join(file)).ok())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn frequency(dir: &Path, files: &[&str]) -> Option<Frequency> {
+    first(dir, files)?.parse().ok().map(Frequency)
+}
+
+/// The cpufreq state of every CPU that has it, ordered by CPU number. Offline CPUs and systems
+/// without cpufreq (most VMs) are skipped.
+pub fn cpus(probe: &Probe) -> Result<Vec<CpuFreq>> {
+    let mut cpus = Vec::new();
+    for path in glob(probe.path("/sys/devices/system/cpu/cpu[0-9]*"))? {
+        let cpu = match path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.trim_start_matches("cpu").parse().ok())
+        {
+            Some(cpu) => cpu,
+            None => continue,
+        };
+        let dir = path.join("cpufreq");
+        if !dir.is_dir() {
+            continue;
+        }
+        cpus.push(CpuFreq {
+            cpu,
+            current: frequency(&dir, &["scaling_cur_freq", "cpuinfo_cur_freq"]),
+            min: frequency(&dir, &["cpuinfo_min_freq", "scaling_min_freq"]),
+            max: frequency(&dir, &["cpuinfo_max_freq", "scaling_max_freq"]),
+            governor: first(&dir, &["scaling_governor"]),
+            driver: first(&dir, &["scaling_driver"]),
+        });
+    }
+    cpus.sort_by_key(|cpu| cpu.cpu);
+    Ok(cpus)
+}
+
+/// The highest boost clock of any CPU.
+pub fn max(probe: &Probe) -> Result<Frequency> {
+    cpus(probe)?
+        .iter()
+        .filter_map(|cpu| cpu.max)
+        .max()
+        .ok_or_else(|| Error::NotFound("cpufreq".to_string()))
+}
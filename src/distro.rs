@@ -1,9 +1,310 @@
-use std::io::Error;
-
-pub fn dist(path: &str) -> Result<String, Error> {
-    let file = std::fs::File::open(path)?;
-    let line: String = crate::shared_functions::line(file, 0); // Expects NAME= to be on first line
-    let distro_vec: Vec<&str> = line.split('=').collect();
-    let distro = distro_vec[1].replace('"', "");
-    Ok(distro)
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::{glob, read};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Where os-release can be found, in order of preference. Bedrock's own file comes first, since
+/// `/etc/os-release` belongs to whichever stratum provides it.
+const OS_RELEASE_PATHS: &[&str] = &[
+    "/bedrock/etc/os-release",
+    "/etc/os-release",
+    "/usr/lib/os-release",
+];
+
+/// Release files of distros that predate os-release, and the name to use if the file only holds a
+/// version. `None` means the name is read from the file, as in "CentOS release 6.10 (Final)".
+const LEGACY_RELEASE_FILES: &[(&str, Option<&str>)] = &[
+    ("/etc/redhat-release", None),
+    ("/etc/gentoo-release", Some("Gentoo")),
+    ("/etc/slackware-version", Some("Slackware")),
+    ("/etc/alpine-release", Some("Alpine Linux")),
+    ("/etc/arch-release", Some("Arch Linux")),
+    ("/etc/debian_version", Some("Debian")),
+];
+
+/// Where a distro's name was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DistroSource {
+    /// An os-release file, such as `/etc/os-release`.
+    OsRelease(PathBuf),
+    /// `/etc/lsb-release`
+    LsbRelease,
+    /// A distro specific file, such as `/etc/redhat-release` or `/etc/debian_version`.
+    ReleaseFile(PathBuf),
+    /// `/etc/issue`
+    Issue,
+}
+
+/// A distro's name along with where it was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistroInfo {
+    pub name: String,
+    pub source: DistroSource,
+}
+
+/// The contents of an `os-release` file, see `man 5 os-release`.
+///
+/// `name`, `id` and `pretty_name` fall back to the defaults given by the spec when they aren't set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OsRelease {
+    /// `NAME`, e.g. "Fedora Linux".
+    pub name: String,
+    /// `PRETTY_NAME`, e.g. "Fedora Linux 39 (Workstation Edition)".
+    pub pretty_name: String,
+    /// `ID`, e.g. "fedora".
+    pub id: String,
+    /// `ID_LIKE`, e.g. `["rhel", "fedora"]` for CentOS.
+    pub id_like: Vec<String>,
+    pub version: Option<String>,
+    pub version_id: Option<String>,
+    pub version_codename: Option<String>,
+    pub build_id: Option<String>,
+    pub variant: Option<String>,
+    pub home_url: Option<String>,
+    /// `ANSI_COLOR`, e.g. "0;34".
+    pub ansi_color: Option<String>,
+    pub logo: Option<String>,
+    /// Every other key, such as `VARIANT_ID` or `SUPPORT_URL`.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl Default for OsRelease {
+    fn default() -> OsRelease {
+        OsRelease {
+            name: "Linux".to_string(),
+            pretty_name: "Linux".to_string(),
+            id: "linux".to_string(),
+            id_like: Vec::new(),
+            version: None,
+            version_id: None,
+            version_codename: None,
+            build_id: None,
+            variant: None,
+            home_url: None,
+            ansi_color: None,
+            logo: None,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+impl OsRelease {
+    /// Parses the contents of an os-release file, `path` is only used in errors.
+    ///
+    /// Blank lines, comments and lines without a `=` are skipped.
+    pub fn parse(contents: &str, path: &str) -> Result<OsRelease> {
+        let mut os_release = OsRelease::default();
+        for (key, value) in variables(contents, path)? {
+            match key.as_str() {
+                "NAME" => os_release.name = value,
+                "PRETTY_NAME" => os_release.pretty_name = value,
+                "ID" => os_release.id = value,
+                "ID_LIKE" => {
+                    os_release.id_like = value.split_whitespace().map(String::from).collect()
+                }
+                "VERSION" => os_release.version = Some(value),
+                "VERSION_ID" => os_release.version_id = Some(value),
+                "VERSION_CODENAME" => os_release.version_codename = Some(value),
+                "BUILD_ID" => os_release.build_id = Some(value),
+                "VARIANT" => os_release.variant = Some(value),
+                "HOME_URL" => os_release.home_url = Some(value),
+                "ANSI_COLOR" => os_release.ansi_color = Some(value),
+                "LOGO" => os_release.logo = Some(value),
+                _ => {
+                    os_release.extra.insert(key, value);
+                }
+            }
+        }
+        Ok(os_release)
+    }
+}
+
+/// Parses `KEY=value` lines as used by os-release and lsb-release, skipping blank lines, comments
+/// and lines without a `=`.
+fn variables(contents: &str, path: &str) -> Result<Vec<(String, String)>> {
+    let mut variables = Vec::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            let value = unquote(value.trim())
+                .map_err(|message| Error::parse(path, Some(number + 1), message))?;
+            variables.push((key.trim().to_string(), value));
+        }
+    }
+    Ok(variables)
+}
+
+/// Undoes shell-style quoting: single quotes are literal, double quotes allow `\` to escape
+/// `$`, `"`, `\` and `` ` ``, and outside of quotes `\` escapes any character.
+fn unquote(value: &str) -> std::result::Result<String, String> {
+    let mut unquoted = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => loop {
+                match chars.next() {
+                    Some('\'') => break,
+                    Some(c) => unquoted.push(c),
+                    None => return Err("unterminated single quote".to_string()),
+                }
+            },
+            '"' => loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.peek() {
+                        Some('$') | Some('"') | Some('\\') | Some('`') => {
+                            unquoted.extend(chars.next())
+                        }
+                        _ => unquoted.push('\\'),
+                    },
+                    Some(c) => unquoted.push(c),
+                    None => return Err("unterminated double quote".to_string()),
+                }
+            },
+            '\\' => unquoted.extend(chars.next()),
+            c => unquoted.push(c),
+        }
+    }
+    Ok(unquoted)
+}
+
+/// Finds and parses the first valid os-release file, along with its path on the probed system.
+fn find_os_release(probe: &Probe) -> Result<(OsRelease, &'static str)> {
+    let mut error = None;
+    for path in OS_RELEASE_PATHS {
+        let real_path = probe.path(path);
+        match read(&real_path) {
+            Ok(contents) => {
+                match OsRelease::parse(&contents, &real_path.display().to_string()) {
+                    Ok(os_release) => return Ok((os_release, path)),
+                    Err(e) => error = Some(e),
+                }
+            }
+            // A broken file says more about what's wrong than a later missing one.
+            Err(_) if matches!(error, Some(Error::ParseError { .. })) => {}
+            Err(e) => error = Some(e),
+        }
+    }
+    Err(error.unwrap_or_else(|| Error::NotFound("os-release".to_string())))
+}
+
+pub fn os_release(probe: &Probe) -> Result<OsRelease> {
+    find_os_release(probe).map(|(os_release, _)| os_release)
+}
+
+fn lsb_release(probe: &Probe) -> Option<String> {
+    let path = probe.path("/etc/lsb-release");
+    let variables = variables(&read(&path).ok()?, &path.display().to_string()).ok()?;
+    let get = |name: &str| {
+        variables
+            .iter()
+            .find(|(key, value)| key == name && !value.is_empty())
+            .map(|(_, value)| value.clone())
+    };
+    get("DISTRIB_ID").or_else(|| get("DISTRIB_DESCRIPTION"))
+}
+
+/// Takes the name from a line like "CentOS Linux release 7.9.2009 (Core)".
+fn name_from_release_line(contents: &str) -> Option<String> {
+    let line = contents.lines().next()?.trim();
+    let name = match line.find(" release") {
+        Some(end) => &line[..end],
+        None => line,
+    };
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+fn release_file(probe: &Probe) -> Option<(String, PathBuf)> {
+    for (path, name) in LEGACY_RELEASE_FILES {
+        if let Ok(contents) = read(probe.path(path)) {
+            let name = match name {
+                Some(name) => Some(name.to_string()),
+                None => name_from_release_line(&contents),
+            };
+            if let Some(name) = name {
+                return Some((name, PathBuf::from(path)));
+            }
+        }
+    }
+
+    // Any other distro specific file, e.g. /etc/mageia-release or /etc/SuSE-release.
+    let mut paths = glob(probe.path("/etc/*-release")).unwrap_or_default();
+    paths.extend(glob(probe.path("/etc/*_version")).unwrap_or_default());
+    paths.into_iter().find_map(|path| {
+        let file_name = path.file_name()?.to_str()?.to_string();
+        if file_name == "os-release" || file_name == "lsb-release" {
+            return None;
+        }
+        let name = name_from_release_line(&read(&path).ok()?)?;
+        Some((name, PathBuf::from("/etc").join(file_name)))
+    })
+}
+
+/// Takes the name from the first line of /etc/issue, dropping the getty escapes like `\n`, `\l` and
+/// `\S{NAME}`, and the brackets left empty by them, as in Arch's `Arch Linux \r (\l)`.
+fn issue(probe: &Probe) -> Option<String> {
+    let contents = read(probe.path("/etc/issue")).ok()?;
+    let line = contents.lines().find(|line| !line.trim().is_empty())?;
+    let mut name = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            chars.next();
+            if chars.peek() == Some(&'{') {
+                chars.by_ref().find(|&c| c == '}');
+            }
+        } else {
+            name.push(c);
+        }
+    }
+    let name = name.replace("()", "");
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    let name = name.trim_start_matches("Welcome to").trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+pub fn detect(probe: &Probe) -> Result<DistroInfo> {
+    let os_release_error = match find_os_release(probe) {
+        Ok((os_release, path)) => {
+            return Ok(DistroInfo {
+                name: os_release.name,
+                source: DistroSource::OsRelease(PathBuf::from(path)),
+            });
+        }
+        Err(e) => e,
+    };
+    if let Some(name) = lsb_release(probe) {
+        return Ok(DistroInfo {
+            name,
+            source: DistroSource::LsbRelease,
+        });
+    }
+    if let Some((name, path)) = release_file(probe) {
+        return Ok(DistroInfo {
+            name,
+            source: DistroSource::ReleaseFile(path),
+        });
+    }
+    if let Some(name) = issue(probe) {
+        return Ok(DistroInfo {
+            name,
+            source: DistroSource::Issue,
+        });
+    }
+    Err(os_release_error)
 }
@@ -0,0 +1,175 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::read;
+
+const DMI_DIR: &str = "/sys/devices/virtual/dmi/id";
+
+/// Chassis types from the SMBIOS spec (DSP0134 7.4.1), starting at 1.
+const CHASSIS_TYPES: &[&str] = &[
+    "Other",
+    "Unknown",
+    "Desktop",
+    "Low Profile Desktop",
+    "Pizza Box",
+    "Mini Tower",
+    "Tower",
+    "Portable",
+    "Laptop",
+    "Notebook",
+    "Hand Held",
+    "Docking Station",
+    "All in One",
+    "Sub Notebook",
+    "Space-saving",
+    "Lunch Box",
+    "Main Server Chassis",
+    "Expansion Chassis",
+    "SubChassis",
+    "Bus Expansion Chassis",
+    "Peripheral Chassis",
+    "RAID Chassis",
+    "Rack Mount Chassis",
+    "Sealed-case PC",
+    "Multi-system Chassis",
+    "Compact PCI",
+    "Advanced TCA",
+    "Blade",
+    "Blade Enclosure",
+    "Tablet",
+    "Convertible",
+    "Detachable",
+    "IoT Gateway",
+    "Embedded PC",
+    "Mini PC",
+    "Stick PC",
+];
+
+/// Strings firmware vendors leave in DMI fields they didn't fill in, compared case-insensitively.
+const PLACEHOLDERS: &[&str] = &[
+    "to be filled by o.e.m.",
+    "system product name",
+    "system version",
+    "system manufacturer",
+    "system serial number",
+    "default string",
+    "not applicable",
+    "not specified",
+    "not available",
+    "none",
+    "oem",
+    "o.e.m.",
+    "undefined",
+    "unknown",
+    "type1productconfigid",
+    "0123456789",
+    "x.x",
+];
+
+/// The identity of the machine as reported by its firmware, from `/sys/devices/virtual/dmi/id/`.
+///
+/// Fields are named after their file. Serial numbers and the UUID are only readable by root, and
+/// are `None` for other users.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dmi {
+    pub sys_vendor: Option<String>,
+    pub product_name: Option<String>,
+    pub product_version: Option<String>,
+    pub product_family: Option<String>,
+    pub product_sku: Option<String>,
+    pub product_serial: Option<String>,
+    pub product_uuid: Option<String>,
+    pub board_vendor: Option<String>,
+    pub board_name: Option<String>,
+    pub board_version: Option<String>,
+    pub board_serial: Option<String>,
+    pub board_asset_tag: Option<String>,
+    pub bios_vendor: Option<String>,
+    pub bios_version: Option<String>,
+    pub bios_date: Option<String>,
+    pub bios_release: Option<String>,
+    pub ec_firmware_release: Option<String>,
+    /// The decoded chassis type, e.g. "Laptop", "Desktop" or "Rack Mount Chassis".
+    pub chassis_type: Option<String>,
+    pub chassis_vendor: Option<String>,
+    pub chassis_version: Option<String>,
+    pub chassis_serial: Option<String>,
+    pub chassis_asset_tag: Option<String>,
+}
+
+/// Whether `value` is one of the strings vendors use for "not set".
+pub fn is_placeholder(value: &str) -> bool {
+    let value = value.trim().to_lowercase();
+    value.is_empty() || PLACEHOLDERS.contains(&value.as_str())
+}
+
+impl Dmi {
+    /// The device's name as "Vendor Product (Version)", leaving out placeholders. Falls back to
+    /// the board when the product isn't set, as on many self-built desktops.
+    pub fn device_name(&self) -> Option<String> {
+        let real = |value: &Option<String>| value.clone().filter(|value| !is_placeholder(value));
+        let (vendor, product, version) = match real(&self.product_name) {
+            Some(product) => (real(&self.sys_vendor), product, real(&self.product_version)),
+            None => (
+                real(&self.board_vendor),
+                real(&self.board_name)?,
+                real(&self.board_version),
+            ),
+        };
+        let mut name = match vendor {
+            // Some vendors repeat themselves, e.g. "HP" and "HP EliteBook 840 G5".
+            Some(vendor) if !product.starts_with(&vendor) => format!("{} {}", vendor, product),
+            _ => product,
+        };
+        if let Some(version) = version {
+            if !name.contains(&version) {
+                name = format!("{} ({})", name, version);
+            }
+        }
+        Some(name)
+    }
+}
+
+pub fn dmi(probe: &Probe) -> Result<Dmi> {
+    let dir = probe.path(DMI_DIR);
+    if !dir.is_dir() {
+        return Err(Error::NotFound(DMI_DIR.to_string()));
+    }
+    let get = |file: &str| {
+        read(dir.join(file))
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let chassis_type = get("chassis_type").map(|kind| {
+        kind.parse::<usize>()
+            .ok()
+            .and_then(|kind| CHASSIS_TYPES.get(kind.checked_sub(1)?))
+            .map(|kind| kind.to_string())
+            .unwrap_or(kind)
+    });
+    Ok(Dmi {
+        sys_vendor: get("sys_vendor"),
+        product_name: get("product_name"),
+        product_version: get("product_version"),
+        product_family: get("product_family"),
+        product_sku: get("product_sku"),
+        product_serial: get("product_serial"),
+        product_uuid: get("product_uuid"),
+        board_vendor: get("board_vendor"),
+        board_name: get("board_name"),
+        board_version: get("board_version"),
+        board_serial: get("board_serial"),
+        board_asset_tag: get("board_asset_tag"),
+        bios_vendor: get("bios_vendor"),
+        bios_version: get("bios_version"),
+        bios_date: get("bios_date"),
+        bios_release: get("bios_release"),
+        ec_firmware_release: get("ec_firmware_release"),
+        chassis_type,
+        chassis_vendor: get("chassis_vendor"),
+        chassis_version: get("chassis_version"),
+        chassis_serial: get("chassis_serial"),
+        chassis_asset_tag: get("chassis_asset_tag"),
+    })
+}
@@ -1,17 +1,21 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
 use std::env;
-use std::io::Error;
 
-pub fn de() -> Result<String, Error> {
-    Ok(env::var("XDG_DESKTOP_SESSION")
+pub fn de() -> Option<String> {
+    env::var("XDG_DESKTOP_SESSION")
         .or_else(|_| env::var("XDG_CURRENT_DESKTOP"))
         .or_else(|_| env::var("DESKTOP_SESSION"))
-        .unwrap_or_else(|_| "N/A".to_string()))
+        .ok()
 }
 
-pub fn wm() -> Result<String, Error> {
-    let path = format!("{}/.xinitrc", env::var("HOME").unwrap());
-    let file = std::fs::File::open(path)?;
-    let contents = crate::shared_functions::read(file).unwrap();
-    let line = contents.lines().last().unwrap();
-    Ok(line.split(' ').last().unwrap().to_string())
+pub fn wm(probe: &Probe) -> Result<String> {
+    let home = env::var("HOME").map_err(|_| Error::NotFound("$HOME".to_string()))?;
+    let path = probe.path(home).join(".xinitrc");
+    let contents = crate::shared_functions::read(&path)?;
+    let line = contents
+        .lines()
+        .next_back()
+        .ok_or_else(|| Error::parse(path.display(), None, "file is empty"))?;
+    Ok(line.split(' ').next_back().unwrap_or(line).to_string())
 }
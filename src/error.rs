@@ -0,0 +1,84 @@
+use std::fmt;
+use std::io;
+
+/// Shorthand for results returned by nixinfo.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The ways gathering a piece of system info can fail.
+#[derive(Debug)]
+pub enum Error {
+    /// A file, command or environment variable does not exist.
+    NotFound(String),
+    /// Something was read, but its contents did not have the expected layout.
+    ParseError {
+        path: String,
+        line: Option<usize>,
+        message: String,
+    },
+    /// The info can not be obtained on this system or with the given input.
+    Unsupported(String),
+    /// A command ran but did not exit successfully, `status` is `None` if it was killed by a signal.
+    CommandFailed { command: String, status: Option<i32> },
+    /// Access to a file was denied.
+    PermissionDenied(String),
+    /// Any other I/O error, along with the path that caused it.
+    Io { path: String, source: io::Error },
+}
+
+impl Error {
+    /// Wraps an I/O error that occurred while accessing `path`, picking the most specific variant.
+    pub fn io<P: fmt::Display>(source: io::Error, path: P) -> Error {
+        match source.kind() {
+            io::ErrorKind::NotFound => Error::NotFound(path.to_string()),
+            io::ErrorKind::PermissionDenied => Error::PermissionDenied(path.to_string()),
+            _ => Error::Io {
+                path: path.to_string(),
+                source,
+            },
+        }
+    }
+
+    /// Creates a `ParseError` for `path`, with an optional 1-based line number.
+    pub fn parse<P: fmt::Display, M: Into<String>>(path: P, line: Option<usize>, message: M) -> Error {
+        Error::ParseError {
+            path: path.to_string(),
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound(what) => write!(f, "{} was not found", what),
+            Error::ParseError {
+                path,
+                line: Some(line),
+                message,
+            } => write!(f, "failed to parse {} (line {}): {}", path, line, message),
+            Error::ParseError { path, message, .. } => {
+                write!(f, "failed to parse {}: {}", path, message)
+            }
+            Error::Unsupported(what) => write!(f, "unsupported: {}", what),
+            Error::CommandFailed {
+                command,
+                status: Some(code),
+            } => write!(f, "`{}` exited with status {}", command, code),
+            Error::CommandFailed { command, .. } => {
+                write!(f, "`{}` was terminated by a signal", command)
+            }
+            Error::PermissionDenied(path) => write!(f, "permission denied for {}", path),
+            Error::Io { path, source } => write!(f, "failed to read {}: {}", path, source),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -1,386 +1,770 @@
 use std::env;
-use std::fs::{read_to_string, File};
-use std::io::Error;
-use std::process::Command;
+use std::time::Duration;
 
-use glob::glob;
-use memory::memory;
-use memory::memory_formatter;
-use shared_functions::read;
+use probe::system;
+#[cfg(feature = "music_playerctl")]
+use shared_functions::run;
+use shared_functions::{glob, read};
+
+pub use android::AndroidInfo;
+pub use bedrock::Stratum;
+pub use cgroup::{Cgroup, CgroupVersion};
+pub use cpu::CpuInfo;
+pub use cpu_usage::{CpuSampler, CpuUsage, UsageBreakdown};
+pub use cpufreq::{CpuFreq, Frequency};
+pub use distro::{DistroInfo, DistroSource, OsRelease};
+pub use dmi::Dmi;
+pub use error::{Error, Result};
+pub use load::{LoadAverage, Pressure, PressureStall, SystemPressure};
+pub use logo::{Logo, LogoSize};
+pub use memory::{MemInfo, MemoryInfo, MemorySize, SwapDevice, ZramDevice, Zswap};
+pub use packages::{PackageCount, PACKAGE_MANAGERS};
+pub use platform::Platform;
+pub use probe::Probe;
+pub use sensors::{Sensor, SensorChip, SensorKind};
+pub use size::{ByteFormat, ByteUnits};
+pub use smbios::MemoryDevice;
+pub use snapshot::SystemSnapshot;
+pub use thermal::{CoolingDevice, TemperatureUnit, ThermalZone, TripPoint, TripPointKind};
+pub use topology::{Cache, CoreKind, CpuTopology, LogicalCpu, NumaNode};
+pub use uptime::Uptime;
+pub use virt::{Virt, VirtKind};
+pub use vulnerabilities::{Smt, SmtControl, Vulnerability, VulnerabilityStatus};
 
+mod android;
+mod bedrock;
+mod cgroup;
 mod cpu;
+mod cpu_usage;
+mod cpufreq;
 mod distro;
+mod dmi;
 mod environment;
+mod error;
+mod load;
+mod logo;
 mod memory;
 mod packages;
+mod platform;
+mod probe;
+mod sensors;
 mod shared_functions;
+mod size;
+mod smbios;
+mod snapshot;
 mod terminal;
+mod thermal;
+mod topology;
 mod uptime;
+mod virt;
+mod vulnerabilities;
 
-/// Obtain the temp of CPU thermal zones. Outputs to a Result<Vec<(String, String)>>
-pub fn temp() -> Result<Vec<(String, String)>, Error> {
-    let paths = glob("/sys/class/thermal/thermal_zone*").expect("Failed to read path");
-    let mut zone_temps: Vec<(String, String)> = Vec::new();
+/// Obtain the value of an Android system property, outputs to a Result<String>
+pub fn android_property(name: &str) -> Result<String> {
+    system().android_property(name)
+}
 
-    for path in paths {
-        let path: std::path::PathBuf = path.unwrap();
-        let path_str: String = path.as_path().to_string_lossy().into_owned().to_string();
-        let zone_name: String = read_to_string(path_str.to_owned() + "/type")?.trim().to_owned();
-        let temp: f64 = read_to_string(path_str.to_owned() + "/temp")?
-            .trim()
-            .parse::<f64>()
-            .unwrap()
-            / 1000.0;
-        zone_temps.push((zone_name, temp.to_string()));
-    }
-    Ok(zone_temps)
+/// Obtain the Android version, SDK level, device and security patch, outputs to a Result<AndroidInfo>
+pub fn android_info() -> Result<AndroidInfo> {
+    system().android_info()
+}
+
+/// Obtain the temperature, fan, voltage, current and power readings of hwmon chips, outputs to a Result<Vec<SensorChip>>
+pub fn sensors() -> Result<Vec<SensorChip>> {
+    system().sensors()
+}
+
+/// Obtain the temp of CPU thermal zones. Outputs to a Result<Vec<ThermalZone>>
+pub fn temp() -> Result<Vec<ThermalZone>> {
+    system().temp()
 }
 
 /// Obtain CPU model, outputs to a Result<String>
-pub fn cpu() -> Result<String, Error> {
-    let file = File::open("/proc/cpuinfo")?;
-    let model = read_to_string("/sys/firmware/devicetree/base/model");
-    fn info(file: File, line: usize) -> Result<String, Error> {
-        let info = cpu::get(file, line);
-        Ok(cpu::format(info).trim().to_string().replace('\n', ""))
-    }
-    if let Ok(model) = model {
-        match model.starts_with("Raspberry") {
-            true => info(file, 1),
-            false => info(file, 4),
-        }
-    } else if shared_functions::exit_code() != 1 {
-        info(file, 1)
-    } else {
-        info(file, 4)
-    }
+pub fn cpu() -> Result<String> {
+    system().cpu()
+}
+
+/// Obtain the parsed contents of /proc/cpuinfo, outputs to a Result<CpuInfo>
+pub fn cpu_info() -> Result<CpuInfo> {
+    system().cpu_info()
+}
+
+/// Obtain the frequencies, governor and driver of each CPU, outputs to a Result<Vec<CpuFreq>>
+pub fn cpu_freq() -> Result<Vec<CpuFreq>> {
+    system().cpu_freq()
+}
+
+/// Obtain the highest boost clock of any CPU, outputs to a Result<Frequency>
+pub fn cpu_max_freq() -> Result<Frequency> {
+    system().cpu_max_freq()
+}
+
+/// Obtain the packages, cores, threads, caches and NUMA nodes of the CPUs, outputs to a Result<CpuTopology>
+pub fn cpu_topology() -> Result<CpuTopology> {
+    system().cpu_topology()
+}
+
+/// Obtain the CPU usage over `interval`, overall and per CPU, outputs to a Result<CpuUsage>
+///
+/// This sleeps for `interval`, use a [`CpuSampler`] to poll without blocking.
+pub fn cpu_usage(interval: Duration) -> Result<CpuUsage> {
+    system().cpu_usage(interval)
+}
+
+/// Obtain the CPU vulnerabilities the kernel knows of and their mitigations, outputs to a Result<Vec<Vulnerability>>
+pub fn cpu_vulnerabilities() -> Result<Vec<Vulnerability>> {
+    system().cpu_vulnerabilities()
+}
+
+/// Obtain the SMT control state, outputs to a Result<Smt>
+pub fn smt() -> Result<Smt> {
+    system().smt()
 }
 
 /// Obtain name of device, outputs to a string
-pub fn device() -> Result<String, Error> {
-    if shared_functions::exit_code() != 1 {
-        let output_product = std::process::Command::new("sh")
-            .args(["-c", "getprop ro.product.name"])
-            .output()
-            .expect("");
-        let product = String::from_utf8_lossy(&output_product.stdout)
-            .trim()
-            .to_string();
-        let output_model = std::process::Command::new("sh")
-            .args(["-c", "getprop ro.product.model"])
-            .output()
-            .expect("");
-        let model = String::from_utf8_lossy(&output_model.stdout)
-            .trim()
-            .to_string();
-        let output_device = std::process::Command::new("sh")
-            .args(["-c", "getprop ro.product.device"])
-            .output()
-            .expect("");
-        let device = String::from_utf8_lossy(&output_device.stdout)
-            .trim()
-            .to_string();
-        let full = [
-            product,
-            " ".to_string(),
-            model,
-            " (".to_string(),
-            device,
-            ")".to_string(),
-        ].concat();
-        Ok(full)
-    } else {
-        let model = read_to_string("/sys/devices/virtual/dmi/id/product_name")
-            .or_else(|_| read_to_string("/sys/firmware/devicetree/base/model"))?;
-        Ok(model.trim().replace('\n', ""))
-    }
+pub fn device() -> Result<String> {
+    system().device()
+}
+
+/// Obtain the firmware's identity of the machine (vendor, product, board, BIOS and chassis), outputs to a Result<Dmi>
+pub fn dmi() -> Result<Dmi> {
+    system().dmi()
 }
 
 /// Obtain the distro name, outputs to a string
-pub fn distro() -> Result<String, Error> {
-    if shared_functions::exit_code() != 1 {
-        let output_distro = std::process::Command::new("sh")
-            .args(["-c", "getprop ro.build.version.release"])
-            .output()
-            .expect("");
-        let mut distro = String::from_utf8_lossy(&output_distro.stdout)
-            .trim()
-            .to_string();
-        distro = ["Android ".to_string(), distro].concat();
-        let output_flavor = std::process::Command::new("sh")
-            .args(["-c", "getprop ro.build.flavor"])
-            .output()
-            .expect("");
-        let flavor = String::from_utf8_lossy(&output_flavor.stdout)
-            .trim()
-            .to_string();
-        let full = [distro, " (".to_string(), flavor, ")".to_string()].concat();
-        Ok(full)
-    } else {
-        let distro = distro::dist("/bedrock/etc/os-release")
-            .or_else(|_| distro::dist("/etc/os-release"))
-            .or_else(|_| distro::dist("/usr/lib/os-release"))?;
-        Ok(distro)
-    }
+pub fn distro() -> Result<String> {
+    system().distro()
+}
+
+/// Obtain the strata of a Bedrock Linux system, outputs to a Result<Vec<Stratum>>
+pub fn bedrock_strata() -> Result<Vec<Stratum>> {
+    system().bedrock_strata()
+}
+
+/// Obtain the distro name and the file it was found in, outputs to a Result<DistroInfo>
+pub fn distro_info() -> Result<DistroInfo> {
+    system().distro_info()
+}
+
+/// Obtain the parsed contents of os-release, outputs to a Result<OsRelease>
+pub fn os_release() -> Result<OsRelease> {
+    system().os_release()
 }
 
 /// Obtains the name of the user's DE or WM, outputs to a string
-pub fn environment() -> Result<String, Error> {
-    let de = environment::de().unwrap();
-    if de == "N/A" {
-        Ok(environment::wm().unwrap())
-    } else {
-        Ok(de)
-    }
+pub fn environment() -> Result<String> {
+    system().environment()
 }
 
 /// Obtain the contents of the env variable specified as an arg, outputs to a string
-pub fn env(var: &str) -> Option<String> {
-    if shared_functions::exit_code() != 1 {
-        if var == "USER" {
-            let output_user = std::process::Command::new("sh")
-                .args(["-c", "whoami"])
-                .output()
-                .expect("");
-            Some(String::from_utf8_lossy(&output_user.stdout).trim().to_string())
+pub fn env(var: &str) -> Result<String> {
+    env::var(var).or_else(|_| {
+        if var == "USER" && system().platform() == Platform::Android {
+            android::user()
         } else {
-            Some(env::var(var).unwrap_or_else(|_| {format!("N/A (could not read ${}, are you sure it's set?)", var)}))
+            Err(Error::NotFound(format!("${}", var)))
         }
-    } else {
-        Some(
-            env::var(var).unwrap_or_else(|_| {format!("N/A (could not read ${}, are you sure it's set?)", var)}))
-    }
+    })
 }
 
 /// Obtain a vector containing the names of the GPUs, outputs to a `Result<Vec<String>>`
-pub fn gpu() -> Result<Vec<String>, Error> {
-    let mut gpu_dev_vec: Vec<String> = Vec::new();
-    let mut gpu_vendor_vec: Vec<String> = Vec::new();
-    for entry in glob("/sys/class/drm/card?/device/").expect("Failed to read glob pattern") {
-        match entry {
-            Ok(path) => {
-                let device_path = format!("{}/device", path.display());
-                let vendor_path = format!("{}/vendor", path.display());
-                let device_id = read_to_string(device_path)?;
-                let vendor_id = read_to_string(vendor_path)?;
-                let true_device_id = device_id.trim();
-                let true_vendor_id = vendor_id.trim();
-                let searchable_device_id = true_device_id.split('x').collect::<Vec<&str>>()[1];
-                let searchable_vendor_id = true_vendor_id.split('x').collect::<Vec<&str>>()[1];
-                gpu_dev_vec.push(searchable_device_id.to_string());
-                gpu_vendor_vec.push(searchable_vendor_id.to_string());
-            },
-            Err(e) => println!("{:?}", e),
-        }
-    }
-    let all_ids = read_to_string("/usr/share/hwdata/pci.ids")?;
-    let mut gpu_names_vec: Vec<String> = Vec::new();
-    let mut found_vendor = false;
-    let id_vec: Vec<&str> = all_ids.split('\n').collect();
-    for line in id_vec {
-        if gpu_vendor_vec.iter().any(|vendor| line.starts_with(vendor)) {
-            found_vendor = true;
-        }
-        if found_vendor {
-            if gpu_dev_vec.iter().any(|device| line.contains(device)) {
-                let split_line = line.split("  ").collect::<Vec<&str>>()[1];
-                gpu_names_vec.push(split_line.trim().to_string());
-                found_vendor=false;
-            } else {
-                continue;
-            }
-        }
-    }
-    Ok(gpu_names_vec)
+pub fn gpu() -> Result<Vec<String>> {
+    system().gpu()
+}
+
+/// Detect what kind of system this is, outputs to a Platform
+pub fn platform() -> Platform {
+    system().platform()
+}
+
+/// Detect the hypervisor if running in a virtual machine, outputs to an Option<Virt>
+pub fn virtualization() -> Option<Virt> {
+    system().virtualization()
 }
 
 /// Obtain the hostname, outputs to a Result<String>
-pub fn hostname() -> Result<String, Error> {
-    if shared_functions::exit_code() != 1 {
-        let output_hostname = std::process::Command::new("sh")
-            .args(["-c", "hostname"])
-            .output()
-            .expect("");
-        Ok(String::from_utf8_lossy(&output_hostname.stdout)
-            .trim()
-            .to_string())
-    } else {
-        Ok(read_to_string("/etc/hostname")?.trim().to_string())
-    }
+pub fn hostname() -> Result<String> {
+    system().hostname()
 }
 
 /// Obtain the kernel version, outputs to a Result<String>
-pub fn kernel() -> Result<String, Error> {
-    Ok(read_to_string("/proc/sys/kernel/osrelease")?
-        .trim()
-        .to_string()
-        .replace('\n', ""))
+pub fn kernel() -> Result<String> {
+    system().kernel()
+}
+
+/// Obtain the ASCII logo of the distro, falling back to Tux, outputs to a Logo
+pub fn logo() -> &'static Logo {
+    system().logo()
+}
+
+/// Obtain free physical memory, outputs to a Result<MemorySize>
+pub fn memory_free() -> Result<MemorySize> {
+    system().memory_free()
+}
+
+/// Obtain available memory for applications (without swap), outputs to a Result<MemorySize>
+pub fn memory_available() -> Result<MemorySize> {
+    system().memory_available()
+}
+
+/// Obtain total memory, outputs to a Result<MemorySize>
+pub fn memory_total() -> Result<MemorySize> {
+    system().memory_total()
+}
+
+/// Obtain used memory by subtracting available memory from total memory, outputs to a Result<MemorySize>
+pub fn memory_used() -> Result<MemorySize> {
+    system().memory_used()
+}
+
+/// Obtain total, free and available memory, outputs to a Result<MemoryInfo>
+pub fn memory() -> Result<MemoryInfo> {
+    system().memory()
+}
+
+/// Obtain total memory, capped by the memory limit of the process's cgroup, outputs to a Result<MemorySize>
+pub fn memory_total_effective() -> Result<MemorySize> {
+    system().memory_total_effective()
+}
+
+/// Obtain the cgroup version and resource limits of the current process, outputs to a Result<Cgroup>
+pub fn cgroup() -> Result<Cgroup> {
+    system().cgroup()
+}
+
+/// Obtain every field of /proc/meminfo, outputs to a Result<MemInfo>
+pub fn meminfo() -> Result<MemInfo> {
+    system().meminfo()
+}
+
+/// Obtain total swap, outputs to a Result<MemorySize>
+pub fn swap_total() -> Result<MemorySize> {
+    system().swap_total()
+}
+
+/// Obtain used swap, outputs to a Result<MemorySize>
+pub fn swap_used() -> Result<MemorySize> {
+    system().swap_used()
 }
 
-// Obtain free physical memory in MBs, outputs to a Result<String>
-pub fn memory_free() -> Result<String, Error> {
-    let (size, unit) = memory("MemFree").unwrap();
-    memory_formatter(size, unit)
+/// Obtain free swap, outputs to a Result<MemorySize>
+pub fn swap_free() -> Result<MemorySize> {
+    system().swap_free()
 }
 
-// Obtain available memory for applications (without swap) in MBs, outputs to a Result<String>
-pub fn memory_available() -> Result<String, Error> {
-    let (size, unit) = memory("MemAvailable").unwrap();
-    memory_formatter(size, unit)
+/// Obtain the installed memory modules from the SMBIOS table, outputs to a Result<Vec<MemoryDevice>>
+///
+/// The table is only readable by root, so this fails with `PermissionDenied` for other users.
+pub fn memory_devices() -> Result<Vec<MemoryDevice>> {
+    system().memory_devices()
 }
 
-/// Obtain total memory in MBs, outputs to a Result<String>
-pub fn memory_total() -> Result<String, Error> {
-    let (size, unit) = memory("MemTotal").unwrap();
-    memory_formatter(size, unit)
+/// Obtain the swap partitions and files in use, outputs to a Result<Vec<SwapDevice>>
+pub fn swaps() -> Result<Vec<SwapDevice>> {
+    system().swaps()
 }
 
-// Obtain used memory in MBs by subtracting free memory from total memory, outputs to a Result<string>
-pub fn memory_used() -> Result<String, Error> {
-    let (total_size, unit) = memory("MemTotal").unwrap();
-    let (free_size, _) = memory("MemFree").unwrap();
+/// Obtain the zram devices and how well they compress, outputs to a Result<Vec<ZramDevice>>
+pub fn zram() -> Result<Vec<ZramDevice>> {
+    system().zram()
+}
 
-    memory_formatter(total_size - free_size, unit)
+/// Obtain the zswap parameters, outputs to a Result<Zswap>
+pub fn zswap() -> Result<Zswap> {
+    system().zswap()
 }
 
 // Music info
 /// Connects to mpd, and obtains music info in the format "artist - album (date) - title", outputs to a String
 #[cfg(feature = "music_mpd")]
-pub fn music() -> Result<String, Box<dyn std::error::Error>> {
-    let mut c = mpd::Client::connect("127.0.0.1:6600")?;
-    let song = c.currentsong().unwrap().unwrap();
+pub fn music() -> Result<String> {
+    const MPD: &str = "127.0.0.1:6600";
+    let mpd_error = |e: mpd::error::Error| match e {
+        mpd::error::Error::Io(e) => Error::io(e, MPD),
+        e => Error::parse(MPD, None, e.to_string()),
+    };
+    let mut c = mpd::Client::connect(MPD).map_err(mpd_error)?;
+    let song = c
+        .currentsong()
+        .map_err(mpd_error)?
+        .ok_or_else(|| Error::NotFound("current mpd song".to_string()))?;
     let na = "N/A".to_string();
-    let tit = song.title.as_ref().unwrap();
-    let art = song.artist.unwrap_or(na);
-    // To find the correct index of `Vec<(String, String)>` containing the proper metadata
-    // we're looking for, we iterate over it and match the position of the element containing
-    // metadata string we're looking for in the first element of the `tuple`.
-    let alb_index = &song.tags.iter().position(|x| x.0 == "Album").unwrap();
-    let dat_index = &song.tags.iter().position(|x| x.0 == "Date").unwrap();
-    let alb = &song.tags[*alb_index].1;
-    let dat = &song.tags[*dat_index].1;
+    let tit = song.title.clone().unwrap_or_else(|| na.clone());
+    let art = song.artist.clone().unwrap_or_else(|| na.clone());
+    // To find the metadata we're looking for in `Vec<(String, String)>`, we iterate over it
+    // and match the element whose first `tuple` element is the metadata string we want.
+    let tag = |name: &str| {
+        song.tags
+            .iter()
+            .find(|x| x.0 == name)
+            .map(|x| x.1.clone())
+            .unwrap_or_else(|| na.clone())
+    };
+    let alb = tag("Album");
+    let dat = tag("Date");
     Ok(format!("{} - {} ({}) - {}", art, alb, dat, tit))
 }
 
 #[cfg(feature = "music_playerctl")]
 /// Gets music info from `playerctl` in the format "artist - album - title", outputs to a String
-pub fn music() -> Result<String, Box<dyn std::error::Error>> {
-    let child = std::process::Command::new("playerctl")
-        .args(&["metadata", "-f", "{{artist}} - {{album}} - {{title}}"])
-        .output();
-    let output;
-    if child.is_ok() {
-        output = String::from_utf8_lossy(&child.unwrap().stdout).to_string();
-    } else {
-        output = String::from("N/A (failed to collect output from `playerctl`)");
-    }
-    Ok(output)
+pub fn music() -> Result<String> {
+    let output = run("playerctl", &["metadata", "-f", "{{artist}} - {{album}} - {{title}}"])?;
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
 /// If neither `music_mpd` nor `music_playerctl` is used.
 #[cfg(not(feature = "music_mpd"))]
 #[cfg(not(feature = "music_playerctl"))]
-pub fn music() -> String {
-    "N/A (music feature must be used to pull in the mpd dependency)".to_string()
+pub fn music() -> Result<String> {
+    Err(Error::Unsupported(
+        "music info requires the `music_mpd` or `music_playerctl` feature".to_string(),
+    ))
+}
+
+/// Obtain the package count of the manager given as an arg, outputs to a Result<PackageCount>
+pub fn packages(manager: &str) -> Result<PackageCount> {
+    system().packages(manager)
+}
+
+/// Gathers all of the info above into one struct, outputs to a SystemSnapshot
+pub fn snapshot() -> SystemSnapshot {
+    system().snapshot()
 }
 
-/// Obtain list of packages based on what manager is given as an arg, outputs to a string
-pub fn packages(manager: &str) -> Result<String, Error> {
-    match manager {
-        "apk" => {
-            let output = Command::new("apk").arg("info").output()?;
-            Ok(format!("{}", packages::count(output)))
+/// Obtain the name of the terminal being used, outputs to a Result<String>
+pub fn terminal() -> Result<String> {
+    system().terminal()
+}
+
+/// Obtain the load averages and task counts from /proc/loadavg, outputs to a Result<LoadAverage>
+pub fn load() -> Result<LoadAverage> {
+    system().load()
+}
+
+/// Obtain the CPU, memory and IO pressure stall information, outputs to a Result<SystemPressure>
+pub fn pressure() -> Result<SystemPressure> {
+    system().pressure()
+}
+
+/// Obtains the current uptime of the system, outputs to a Result<Uptime>
+pub fn uptime() -> Result<Uptime> {
+    system().uptime()
+}
+
+impl Probe {
+    /// Obtain the value of an Android system property, outputs to a Result<String>
+    pub fn android_property(&self, name: &str) -> Result<String> {
+        android::property(self, name)
+    }
+
+    /// Obtain the Android version, SDK level, device and security patch, outputs to a Result<AndroidInfo>
+    pub fn android_info(&self) -> Result<AndroidInfo> {
+        if self.platform() != Platform::Android {
+            return Err(Error::Unsupported("android_info() on a non-Android system".to_string()));
+        }
+        Ok(android::info(self))
+    }
+
+    /// Obtain the temperature, fan, voltage, current and power readings of hwmon chips, outputs to a Result<Vec<SensorChip>>
+    pub fn sensors(&self) -> Result<Vec<SensorChip>> {
+        sensors::chips(self)
+    }
+
+    /// Obtain the temp of CPU thermal zones. Outputs to a Result<Vec<ThermalZone>>
+    pub fn temp(&self) -> Result<Vec<ThermalZone>> {
+        let paths = glob(self.path("/sys/class/thermal/thermal_zone*"))?;
+        // Zones that can't be read are left out rather than failing the whole call.
+        Ok(paths.iter().filter_map(|path| thermal::zone(path)).collect())
+    }
+
+    /// Obtain CPU model, outputs to a Result<String>
+    pub fn cpu(&self) -> Result<String> {
+        let model_name = self
+            .cpu_info()?
+            .model_name
+            .ok_or_else(|| Error::NotFound("CPU model name".to_string()))?;
+        let model_name = cpu::format(model_name);
+        match self.cpu_max_freq() {
+            Ok(frequency) => {
+                // Intel puts the base clock in the name, e.g. "Intel Core i7-8700 CPU @ 3.20GHz".
+                let model_name = model_name.split(" @ ").next().unwrap_or(&model_name);
+                Ok(format!("{} @ {}", model_name, frequency))
+            }
+            Err(_) => Ok(model_name),
         }
-        "apt" | "dpkg" => {
-            let file = File::open("/var/lib/dpkg/status")?;
-            let file_contents = read(file)?;
-            let content_split = file_contents.split('\n');
-            let mut installed_vec: Vec<&str> = Vec::new();
-            for line in content_split {
-                if line.contains("install ok installed") {
-                    installed_vec.push(line);
+    }
+
+    /// Obtain the frequencies, governor and driver of each CPU, outputs to a Result<Vec<CpuFreq>>
+    pub fn cpu_freq(&self) -> Result<Vec<CpuFreq>> {
+        cpufreq::cpus(self)
+    }
+
+    /// Obtain the highest boost clock of any CPU, outputs to a Result<Frequency>
+    pub fn cpu_max_freq(&self) -> Result<Frequency> {
+        cpufreq::max(self)
+    }
+
+    /// Obtain the packages, cores, threads, caches and NUMA nodes of the CPUs, outputs to a Result<CpuTopology>
+    pub fn cpu_topology(&self) -> Result<CpuTopology> {
+        topology::topology(self)
+    }
+
+    /// Obtain the CPU usage over `interval`, overall and per CPU, outputs to a Result<CpuUsage>
+    ///
+    /// This sleeps for `interval`, use a [`CpuSampler`] to poll without blocking.
+    pub fn cpu_usage(&self, interval: Duration) -> Result<CpuUsage> {
+        cpu_usage::usage(self, interval)
+    }
+
+    /// Obtain the CPU vulnerabilities the kernel knows of and their mitigations, outputs to a Result<Vec<Vulnerability>>
+    pub fn cpu_vulnerabilities(&self) -> Result<Vec<Vulnerability>> {
+        vulnerabilities::vulnerabilities(self)
+    }
+
+    /// Obtain the SMT control state, outputs to a Result<Smt>
+    pub fn smt(&self) -> Result<Smt> {
+        vulnerabilities::smt(self)
+    }
+
+    /// Obtain the parsed contents of /proc/cpuinfo, outputs to a Result<CpuInfo>
+    pub fn cpu_info(&self) -> Result<CpuInfo> {
+        let path = self.path("/proc/cpuinfo");
+        CpuInfo::parse(&read(&path)?, &path.display().to_string())
+    }
+
+    /// Obtain name of device, outputs to a string
+    pub fn device(&self) -> Result<String> {
+        match self.platform() {
+            Platform::Android => android::device(self),
+            platform => {
+                let model = self
+                    .dmi()
+                    .ok()
+                    .and_then(|dmi| dmi.device_name())
+                    .map(Ok)
+                    .unwrap_or_else(|| read(self.path("/sys/firmware/devicetree/base/model")));
+                match model {
+                    // The devicetree model ends with a NUL.
+                    Ok(model) => Ok(model.trim_end_matches('\0').trim().replace('\n', "")),
+                    Err(_) if platform == Platform::Wsl => {
+                        Ok("Windows Subsystem for Linux".to_string())
+                    }
+                    // Containers without access to the host's DMI are named after their runtime.
+                    Err(_) if platform == Platform::Container => {
+                        Ok(match platform::runtime(self) {
+                            Some(runtime) => format!("{} container", runtime),
+                            None => "Container".to_string(),
+                        })
+                    }
+                    Err(e) => Err(e),
                 }
             }
-            let count = installed_vec.len();
-            Ok(format!("{}", count))
         }
-        "dnf" => {
-            let output = Command::new("dnf").args(["list", "installed"]).output()?;
-            Ok(format!("{}", packages::count(output)))
+    }
+
+    /// Obtain the firmware's identity of the machine (vendor, product, board, BIOS and chassis), outputs to a Result<Dmi>
+    pub fn dmi(&self) -> Result<Dmi> {
+        dmi::dmi(self)
+    }
+
+    /// Detect the hypervisor if running in a virtual machine, outputs to an Option<Virt>
+    pub fn virtualization(&self) -> Option<Virt> {
+        virt::virtualization(self)
+    }
+
+    /// Obtain the distro name, outputs to a string
+    pub fn distro(&self) -> Result<String> {
+        if self.platform() == Platform::Android {
+            android::distro(self)
+        } else {
+            Ok(self.distro_info()?.name)
         }
-        "eopkg" => {
-            let output = Command::new("eopkg").arg("list-installed").output()?;
-            Ok(format!("{}", packages::count(output)))
+    }
+
+    /// Obtain the strata of a Bedrock Linux system, outputs to a Result<Vec<Stratum>>
+    pub fn bedrock_strata(&self) -> Result<Vec<Stratum>> {
+        bedrock::strata(self)
+    }
+
+    /// Obtain the distro name and the file it was found in, outputs to a Result<DistroInfo>
+    pub fn distro_info(&self) -> Result<DistroInfo> {
+        distro::detect(self)
+    }
+
+    /// Obtain the parsed contents of os-release, outputs to a Result<OsRelease>
+    pub fn os_release(&self) -> Result<OsRelease> {
+        distro::os_release(self)
+    }
+
+    /// Obtains the name of the user's DE or WM, outputs to a string
+    pub fn environment(&self) -> Result<String> {
+        match environment::de() {
+            Some(de) => Ok(de),
+            None => environment::wm(self),
         }
-        "flatpak" => {
-            let output = Command::new("flatpak").args(["list"]).output()?;
-            Ok(format!("{}", packages::count(output)))
+    }
+
+    /// Obtain a vector containing the names of the GPUs, outputs to a `Result<Vec<String>>`
+    pub fn gpu(&self) -> Result<Vec<String>> {
+        let mut gpu_dev_vec: Vec<String> = Vec::new();
+        let mut gpu_vendor_vec: Vec<String> = Vec::new();
+        for path in glob(self.path("/sys/class/drm/card?/device/"))? {
+            let device_id = read(path.join("device"))?;
+            let vendor_id = read(path.join("vendor"))?;
+            let searchable_device_id = device_id.trim().trim_start_matches("0x");
+            let searchable_vendor_id = vendor_id.trim().trim_start_matches("0x");
+            gpu_dev_vec.push(searchable_device_id.to_string());
+            gpu_vendor_vec.push(searchable_vendor_id.to_string());
         }
-        "pacman" => {
-            let mut list: Vec<String> = Vec::new();
-            for entry in glob("/var/lib/pacman/local/*").expect("Failed to read glob pattern") {
-                match entry {
-                    Ok(path) => list.push(path.display().to_string()),
-                    Err(e) => println!("{:?}", e),
+        let all_ids = read(self.path("/usr/share/hwdata/pci.ids"))?;
+        let mut gpu_names_vec: Vec<String> = Vec::new();
+        let mut found_vendor = false;
+        let id_vec: Vec<&str> = all_ids.split('\n').collect();
+        for line in id_vec {
+            if gpu_vendor_vec.iter().any(|vendor| line.starts_with(vendor)) {
+                found_vendor = true;
+            }
+            if found_vendor {
+                if gpu_dev_vec.iter().any(|device| line.contains(device)) {
+                    if let Some((_, name)) = line.split_once("  ") {
+                        gpu_names_vec.push(name.trim().to_string());
+                    }
+                    found_vendor=false;
+                } else {
+                    continue;
                 }
             }
-            let total = list.len() - 1; // -1 to deal with `ALPM_DB_VERSION` file
-            Ok(format!("{}", total))
         }
-        "pip" => {
-            let output = Command::new("pip").arg("list").output()?;
-            Ok(format!("{}", packages::count(output) - 2)) // -2 to deal with header lines in output
+        Ok(gpu_names_vec)
+    }
+
+    /// Obtain the hostname, outputs to a Result<String>
+    pub fn hostname(&self) -> Result<String> {
+        // Android and containers often don't have /etc/hostname, but the kernel always knows it.
+        // A container's /etc/hostname can also be left over from building the image, while the
+        // runtime sets the kernel's hostname for the container's UTS namespace.
+        let etc = || read(self.path("/etc/hostname"));
+        let kernel = || read(self.path("/proc/sys/kernel/hostname"));
+        let hostname = if self.platform() == Platform::Container {
+            kernel().or_else(|_| etc())?
+        } else {
+            etc().or_else(|_| kernel())?
+        };
+        Ok(hostname.trim().to_string())
+    }
+
+    /// Obtain the kernel version, outputs to a Result<String>
+    pub fn kernel(&self) -> Result<String> {
+        Ok(read(self.path("/proc/sys/kernel/osrelease"))?
+            .trim()
+            .to_string()
+            .replace('\n', ""))
+    }
+
+    /// Obtain the ASCII logo of the distro, falling back to Tux, outputs to a Logo
+    pub fn logo(&self) -> &'static Logo {
+        if self.platform() == Platform::Android {
+            return Logo::for_id("android").unwrap_or_else(Logo::tux);
         }
-        "portage" => {
-            let content = read(File::open("/var/lib/portage/world").unwrap()).unwrap();
-            let file_vector: Vec<&str> = content.split('\n').collect();
-            let mut list: Vec<String> = Vec::new();
-            for entry in glob("/var/db/pkg/*/*/").expect("Failed to read glob pattern") {
-                match entry {
-                    Ok(path) => list.push(path.display().to_string()),
-                    Err(e) => println!("{:?}", e),
+        self.os_release()
+            .map(|os_release| Logo::for_os_release(&os_release))
+            .unwrap_or_else(|_| Logo::tux())
+    }
+
+    /// Obtain free physical memory, outputs to a Result<MemorySize>
+    pub fn memory_free(&self) -> Result<MemorySize> {
+        Ok(MemorySize(self.meminfo()?.mem_free))
+    }
+
+    /// Obtain available memory for applications (without swap), outputs to a Result<MemorySize>
+    pub fn memory_available(&self) -> Result<MemorySize> {
+        Ok(MemorySize(self.meminfo()?.available()))
+    }
+
+    /// Obtain total memory, outputs to a Result<MemorySize>
+    pub fn memory_total(&self) -> Result<MemorySize> {
+        Ok(MemorySize(self.meminfo()?.mem_total))
+    }
+
+    /// Obtain used memory by subtracting available memory from total memory, outputs to a Result<MemorySize>
+    pub fn memory_used(&self) -> Result<MemorySize> {
+        Ok(MemorySize(self.meminfo()?.used()))
+    }
+
+    /// Obtain total, free and available memory, outputs to a Result<MemoryInfo>
+    pub fn memory(&self) -> Result<MemoryInfo> {
+        let meminfo = self.meminfo()?;
+        Ok(MemoryInfo {
+            total_bytes: meminfo.mem_total,
+            free_bytes: meminfo.mem_free,
+            available_bytes: meminfo.available(),
+        })
+    }
+
+    /// Obtain total memory, capped by the memory limit of the process's cgroup, outputs to a Result<MemorySize>
+    ///
+    /// Inside a container `memory_total()` is the host's memory, this is what the container can use.
+    pub fn memory_total_effective(&self) -> Result<MemorySize> {
+        let total = self.meminfo()?.mem_total;
+        let limit = self.cgroup().ok().and_then(|cgroup| cgroup.memory_max);
+        Ok(MemorySize(limit.map_or(total, |limit| limit.min(total))))
+    }
+
+    /// Obtain the cgroup version and resource limits of the current process, outputs to a Result<Cgroup>
+    pub fn cgroup(&self) -> Result<Cgroup> {
+        cgroup::cgroup(self)
+    }
+
+    /// Obtain every field of /proc/meminfo, outputs to a Result<MemInfo>
+    pub fn meminfo(&self) -> Result<MemInfo> {
+        memory::meminfo(self)
+    }
+
+    /// Obtain total swap, outputs to a Result<MemorySize>
+    pub fn swap_total(&self) -> Result<MemorySize> {
+        Ok(MemorySize(self.meminfo()?.swap_total))
+    }
+
+    /// Obtain used swap, outputs to a Result<MemorySize>
+    pub fn swap_used(&self) -> Result<MemorySize> {
+        Ok(MemorySize(self.meminfo()?.swap_used()))
+    }
+
+    /// Obtain free swap, outputs to a Result<MemorySize>
+    pub fn swap_free(&self) -> Result<MemorySize> {
+        Ok(MemorySize(self.meminfo()?.swap_free))
+    }
+
+    /// Obtain the installed memory modules from the SMBIOS table, outputs to a Result<Vec<MemoryDevice>>
+    ///
+    /// The table is only readable by root, so this fails with `PermissionDenied` for other users.
+    pub fn memory_devices(&self) -> Result<Vec<MemoryDevice>> {
+        smbios::memory_devices(self)
+    }
+
+    /// Obtain the swap partitions and files in use, outputs to a Result<Vec<SwapDevice>>
+    pub fn swaps(&self) -> Result<Vec<SwapDevice>> {
+        memory::swaps(self)
+    }
+
+    /// Obtain the zram devices and how well they compress, outputs to a Result<Vec<ZramDevice>>
+    pub fn zram(&self) -> Result<Vec<ZramDevice>> {
+        memory::zram(self)
+    }
+
+    /// Obtain the zswap parameters, outputs to a Result<Zswap>
+    pub fn zswap(&self) -> Result<Zswap> {
+        memory::zswap(self)
+    }
+
+    /// Obtain the package count of the manager given as an arg, outputs to a Result<PackageCount>
+    pub fn packages(&self, manager: &str) -> Result<PackageCount> {
+        match manager {
+            "apk" => {
+                let output = self.run("apk", &["info"])?;
+                Ok(PackageCount::total(packages::count(output)))
+            }
+            "apt" | "dpkg" => {
+                let file_contents = read(self.path("/var/lib/dpkg/status"))?;
+                let content_split = file_contents.split('\n');
+                let mut installed_vec: Vec<&str> = Vec::new();
+                for line in content_split {
+                    if line.contains("install ok installed") {
+                        installed_vec.push(line);
+                    }
                 }
+                let count = installed_vec.len();
+                Ok(PackageCount::total(count))
             }
-            Ok(format!(
-                "{} (explicit), {} (total)",
-                file_vector.len() - 1,
-                list.len()
-            ))
-        }
-        "rpm" => {
-            let output = Command::new("rpm").args(["-q", "-a"]).output()?;
-            Ok(format!("{}", packages::count(output)))
+            "dnf" => {
+                let output = self.run("dnf", &["list", "installed"])?;
+                Ok(PackageCount::total(packages::count(output)))
+            }
+            "eopkg" => {
+                let output = self.run("eopkg", &["list-installed"])?;
+                Ok(PackageCount::total(packages::count(output)))
+            }
+            "flatpak" => {
+                let output = self.run("flatpak", &["list"])?;
+                Ok(PackageCount::total(packages::count(output)))
+            }
+            "pacman" => {
+                let local = self.path("/var/lib/pacman/local");
+                if !local.is_dir() {
+                    return Err(Error::NotFound(local.display().to_string()));
+                }
+                let list = glob(local.join("*"))?;
+                let total = list.len().saturating_sub(1); // -1 to deal with `ALPM_DB_VERSION` file
+                Ok(PackageCount::total(total))
+            }
+            "pip" => {
+                let output = self.run("pip", &["list"])?;
+                Ok(PackageCount::total(packages::count(output).saturating_sub(2))) // -2 to deal with header lines in output
+            }
+            "portage" => {
+                let content = read(self.path("/var/lib/portage/world"))?;
+                let file_vector: Vec<&str> = content.split('\n').collect();
+                let list = glob(self.path("/var/db/pkg/*/*/"))?;
+                Ok(PackageCount {
+                    explicit: Some(file_vector.len() as u64 - 1),
+                    total: list.len() as u64,
+                })
+            }
+            "rpm" => {
+                let output = self.run("rpm", &["-q", "-a"])?;
+                Ok(PackageCount::total(packages::count(output)))
+            }
+            "xbps" => {
+                let output = self.run("xbps-query", &["-l"])?;
+                Ok(PackageCount::total(packages::count(output)))
+            }
+            _ => Err(Error::Unsupported(format!(
+                "{} is not supported, please file a bug to get it added!",
+                manager
+            ))),
         }
-        "xbps" => {
-            let output = Command::new("xbps-query").arg("-l").output()?;
-            Ok(format!("{}", packages::count(output)))
+    }
+
+    /// Gathers all of the info above into one struct, outputs to a SystemSnapshot
+    pub fn snapshot(&self) -> SystemSnapshot {
+        snapshot::snapshot(self)
+    }
+
+    /// Obtain the name of the terminal being used, outputs to a Result<String>
+    pub fn terminal(&self) -> Result<String> {
+        let id = std::process::id().to_string();
+        let process_id = terminal::ppid(self, &id)?;
+        let process_name = terminal::name(self, &process_id)?;
+        let info = terminal::info(self, process_name, process_id)?;
+        if info == "systemd" || info.is_empty() {
+            Err(Error::NotFound(
+                "terminal (this could be an issue of using tmux)".to_string(),
+            ))
+        } else {
+            Ok(info)
         }
-        _ => Ok(format!(
-            "N/A ({} is not supported, please file a bug to get it added!)",
-            manager
-        )),
     }
-}
 
-/// Obtain the name of the terminal being used, outputs to a Result<String>
-pub fn terminal() -> Result<String, Error> {
-    let id = std::process::id();
-    let path = format!("/proc/{}/status", id);
-    let process_id = terminal::ppid(File::open(path)?).trim().replace('\n', "");
-    let process_name = terminal::name(process_id.clone()).trim().replace('\n', "");
-    let info = terminal::info(process_name, process_id).unwrap();
-    if info == "systemd" || info.is_empty() {
-        Ok("N/A (could not determine the terminal, this could be an issue of using tmux)".to_string())
-    } else {
-        Ok(info)
-    }
-}
-
-/// Obtains the current uptime of the system, outputs to a Result<String>
-pub fn uptime() -> Result<String, Error> {
-    let raw_uptime = read_to_string("/proc/uptime")?;
-    let uptime_vec: Vec<&str> = raw_uptime.split('.').collect();
-    let uptime = uptime_vec[0].parse::<i64>().unwrap();
-    let (days, hours, minutes) = uptime::duration(uptime);
-    Ok(format!("{} {} {}", days, hours, minutes).trim().to_string())
+    /// Obtain the load averages and task counts from /proc/loadavg, outputs to a Result<LoadAverage>
+    pub fn load(&self) -> Result<LoadAverage> {
+        load::load(self)
+    }
+
+    /// Obtain the CPU, memory and IO pressure stall information, outputs to a Result<SystemPressure>
+    pub fn pressure(&self) -> Result<SystemPressure> {
+        load::pressure(self)
+    }
+
+    /// Obtains the current uptime of the system, outputs to a Result<Uptime>
+    pub fn uptime(&self) -> Result<Uptime> {
+        let path = self.path("/proc/uptime");
+        let raw_uptime = read(&path)?;
+        let seconds = raw_uptime
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .parse::<f64>()
+            .map_err(|e| Error::parse(path.display(), Some(1), e.to_string()))?;
+        Ok(Uptime(Duration::from_secs_f64(seconds)))
+    }
 }
@@ -0,0 +1,126 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::read;
+
+/// The contents of `/proc/loadavg`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+    /// Tasks that are currently runnable.
+    pub running: u32,
+    /// All tasks on the system.
+    pub tasks: u32,
+    /// The PID most recently handed out.
+    pub last_pid: u32,
+}
+
+/// One line of a `/proc/pressure/` file: the share of time tasks were stalled on a resource,
+/// in percent over the last 10, 60 and 300 seconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PressureStall {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    /// The total stall time in microseconds.
+    pub total: u64,
+}
+
+/// Pressure stall information for one resource, see the kernel's `Documentation/accounting/psi.rst`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pressure {
+    /// Time at least some tasks were stalled.
+    pub some: PressureStall,
+    /// Time all non-idle tasks were stalled at once. The kernel only reports this for CPU since
+    /// 5.13.
+    pub full: Option<PressureStall>,
+}
+
+/// Pressure stall information for CPU, memory and IO.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemPressure {
+    pub cpu: Pressure,
+    pub memory: Pressure,
+    pub io: Pressure,
+}
+
+pub fn load(probe: &Probe) -> Result<LoadAverage> {
+    let path = probe.path("/proc/loadavg");
+    let contents = read(&path)?;
+    let error = || {
+        Error::parse(
+            path.display(),
+            Some(1),
+            "expected `1m 5m 15m running/total pid`",
+        )
+    };
+    let fields: Vec<&str> = contents.split_whitespace().collect();
+    let (running, tasks) = fields
+        .get(3)
+        .and_then(|tasks| tasks.split_once('/'))
+        .ok_or_else(error)?;
+    let average = |i: usize| fields.get(i).and_then(|field| field.parse().ok());
+    Ok(LoadAverage {
+        one: average(0).ok_or_else(error)?,
+        five: average(1).ok_or_else(error)?,
+        fifteen: average(2).ok_or_else(error)?,
+        running: running.parse().map_err(|_| error())?,
+        tasks: tasks.parse().map_err(|_| error())?,
+        last_pid: fields
+            .get(4)
+            .and_then(|pid| pid.parse().ok())
+            .ok_or_else(error)?,
+    })
+}
+
+/// Parses a line such as `some avg10=0.00 avg60=0.00 avg300=0.00 total=0`.
+fn stall(line: &str) -> Option<PressureStall> {
+    let mut stall = PressureStall::default();
+    for field in line.split_whitespace().skip(1) {
+        let (key, value) = field.split_once('=')?;
+        match key {
+            "avg10" => stall.avg10 = value.parse().ok()?,
+            "avg60" => stall.avg60 = value.parse().ok()?,
+            "avg300" => stall.avg300 = value.parse().ok()?,
+            "total" => stall.total = value.parse().ok()?,
+            _ => {}
+        }
+    }
+    Some(stall)
+}
+
+fn resource(probe: &Probe, name: &str) -> Result<Pressure> {
+    let path = probe.path("/proc/pressure").join(name);
+    let contents = read(&path)?;
+    let mut some = None;
+    let mut full = None;
+    for (number, line) in contents.lines().enumerate() {
+        let kind = line.split_whitespace().next();
+        if kind != Some("some") && kind != Some("full") {
+            continue;
+        }
+        let parsed = stall(line).ok_or_else(|| {
+            Error::parse(path.display(), Some(number + 1), "invalid pressure line")
+        })?;
+        if kind == Some("some") {
+            some = Some(parsed);
+        } else {
+            full = Some(parsed);
+        }
+    }
+    let some = some.ok_or_else(|| Error::parse(path.display(), None, "missing `some` line"))?;
+    Ok(Pressure { some, full })
+}
+
+pub fn pressure(probe: &Probe) -> Result<SystemPressure> {
+    Ok(SystemPressure {
+        cpu: resource(probe, "cpu")?,
+        memory: resource(probe, "memory")?,
+        io: resource(probe, "io")?,
+    })
+}
@@ -0,0 +1,588 @@
+use crate::distro::OsRelease;
+
+/// Which variant of a logo to use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogoSize {
+    /// Around 7 lines tall, for compact output.
+    Small,
+    /// Around 20 lines tall, like neofetch.
+    Large,
+}
+
+/// ASCII art of a distro's logo.
+///
+/// The art marks where colors change with `${c1}` to `${c4}` placeholders, like neofetch does,
+/// which `render` turns into ANSI escapes and `plain` removes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Logo {
+    /// The os-release IDs this logo is used for.
+    pub ids: &'static [&'static str],
+    /// The ANSI SGR parameters for `${c1}`, `${c2}`, ..., e.g. "34" for blue.
+    pub colors: &'static [&'static str],
+    pub small: &'static str,
+    pub large: &'static str,
+}
+
+impl Logo {
+    /// Finds the logo for an os-release `ID`.
+    pub fn for_id(id: &str) -> Option<&'static Logo> {
+        LOGOS.iter().find(|logo| logo.ids.contains(&id))
+    }
+
+    /// Finds the logo for `ID`, then each of `ID_LIKE`, falling back to Tux.
+    pub fn for_os_release(os_release: &OsRelease) -> &'static Logo {
+        std::iter::once(&os_release.id)
+            .chain(&os_release.id_like)
+            .find_map(|id| Logo::for_id(id))
+            .unwrap_or(&TUX)
+    }
+
+    /// The generic Tux logo.
+    pub fn tux() -> &'static Logo {
+        &TUX
+    }
+
+    /// The art with its color placeholders left in.
+    pub fn art(&self, size: LogoSize) -> &'static str {
+        match size {
+            LogoSize::Small => self.small,
+            LogoSize::Large => self.large,
+        }
+    }
+
+    /// The art with ANSI colors. `ansi_color` is os-release's `ANSI_COLOR`, which replaces the
+    /// logo's first color when given.
+    pub fn render(&self, size: LogoSize, ansi_color: Option<&str>) -> String {
+        self.render_lines(size, ansi_color).join("\n")
+    }
+
+    /// Like `render`, but each line sets its own color and resets it at the end, so text can be
+    /// printed next to the lines.
+    pub fn render_lines(&self, size: LogoSize, ansi_color: Option<&str>) -> Vec<String> {
+        let mut current = String::new();
+        self.art(size)
+            .lines()
+            .map(|line| {
+                let mut rendered = current.clone();
+                for (text, color) in segments(line) {
+                    rendered.push_str(text);
+                    if let Some(n) = color {
+                        current = format!("\x1b[0;{}m", self.color(n, ansi_color));
+                        rendered.push_str(&current);
+                    }
+                }
+                rendered + "\x1b[0m"
+            })
+            .collect()
+    }
+
+    /// The art without any colors.
+    pub fn plain(&self, size: LogoSize) -> String {
+        self.art(size)
+            .lines()
+            .map(|line| segments(line).map(|(text, _)| text).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The width of the art in characters, for placing text next to it.
+    pub fn width(&self, size: LogoSize) -> usize {
+        self.plain(size)
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The SGR parameters for `${cN}`. Art that uses more colors than the logo has reuses the
+    /// last one.
+    fn color<'a>(&'a self, n: usize, ansi_color: Option<&'a str>) -> &'a str {
+        match (n, ansi_color) {
+            (1, Some(ansi_color)) => ansi_color,
+            _ => self
+                .colors
+                .get(n.saturating_sub(1))
+                .or_else(|| self.colors.last())
+                .copied()
+                .unwrap_or("0"),
+        }
+    }
+}
+
+/// Splits a line of art into the text before each `${cN}` placeholder and the placeholder's `N`.
+fn segments(line: &str) -> impl Iterator<Item = (&str, Option<usize>)> {
+    let mut rest = Some(line);
+    std::iter::from_fn(move || {
+        let line = rest?;
+        let placeholder = line.find("${c").and_then(|start| {
+            let end = start + line[start..].find('}')?;
+            let n = line[start + 3..end].parse().ok()?;
+            Some((start, end, n))
+        });
+        match placeholder {
+            Some((start, end, n)) => {
+                rest = Some(&line[end + 1..]);
+                Some((&line[..start], Some(n)))
+            }
+            None => {
+                rest = None;
+                Some((line, None))
+            }
+        }
+    })
+}
+
+const LOGOS: &[Logo] = &[
+    ALPINE,
+    ANDROID,
+    ARCH,
+    BEDROCK,
+    CENTOS,
+    DEBIAN,
+    FEDORA,
+    GENTOO,
+    MANJARO,
+    MINT,
+    NIXOS,
+    OPENSUSE,
+    UBUNTU,
+    VOID,
+];
+
+const ALPINE: Logo = Logo {
+    ids: &["alpine"],
+    colors: &["34", "37"],
+    small: r##"${c1}   /\ /\
+  /${c2}/ ${c1}\  \
+ /${c2}/   ${c1}\  \
+/${c2}//    ${c1}\  \
+${c2}//      ${c1}\  \
+         \"##,
+    large: r##"${c1}       .hddddddddddddddddddddddh.
+      :dddddddddddddddddddddddddd:
+     /dddddddddddddddddddddddddddd/
+    +dddddddddddddddddddddddddddddd+
+  `sdddddddddddddddddddddddddddddddds`
+ `ydddddddddddd++hdddddddddddddddddddy`
+.hddddddddddd+`  `+ddddh:-sdddddddddddh.
+hdddddddddd+`      `+y:    .sddddddddddh
+ddddddddh+`   `//`   `.`     -sddddddddd
+ddddddh+`   `/hddh/`   `:s-    -sddddddd
+ddddh+`   `/+/dddddh/`   `+s-    -sddddd
+ddd+`   `/o` :dddddddh/`   `oy-    .yddd
+hdddyo+ohddyosdddddddddho+oydddy++ohdddh
+.hddddddddddddddddddddddddddddddddddddh.
+ `yddddddddddddddddddddddddddddddddddy`
+  `sdddddddddddddddddddddddddddddddds`
+    +dddddddddddddddddddddddddddddd+
+     /dddddddddddddddddddddddddddd/
+      :dddddddddddddddddddddddddd:
+       .hddddddddddddddddddddddh."##,
+};
+
+const ANDROID: Logo = Logo {
+    ids: &["android"],
+    colors: &["32", "37"],
+    small: r##"${c1}  ;,           ,;
+   ';,.-----.,;'
+  ,'           ',
+ /    O     O    \
+|                 |
+'-----------------'"##,
+    large: r##"${c1}         -o          o-
+          +hydNNNNdyh+
+        +mMMMMMMMMMMMMm+
+      `dMM${c2}m:${c1}NMMMMMMN${c2}:m${c1}MMd`
+      hMMMMMMMMMMMMMMMMMMh
+  ..  yyyyyyyyyyyyyyyyyyyy  ..
+.mMMm`MMMMMMMMMMMMMMMMMMMM`mMMm.
+:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:
+:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:
+:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:
+:MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM:
+-MMMM-MMMMMMMMMMMMMMMMMMMM-MMMM-
+ +yy+ MMMMMMMMMMMMMMMMMMMM +yy+
+      mMMMMMMMMMMMMMMMMMMm
+      `/++MMMMh++hMMMM++/`
+          MMMMo  oMMMM
+          MMMMo  oMMMM
+          oNMm-  -mMNs"##,
+};
+
+const ARCH: Logo = Logo {
+    ids: &["arch", "archarm"],
+    colors: &["36", "36"],
+    small: r##"${c1}      /\
+     /  \
+    /\   \
+${c2}   /      \
+  /   ,,   \
+ /   |  |  -\
+/_-''    ''-_\"##,
+    large: r##"${c1}                   -`
+                  .o+`
+                 `ooo/
+                `+oooo:
+               `+oooooo:
+               -+oooooo+:
+             `/:-:++oooo+:
+            `/++++/+++++++:
+           `/++++++++++++++:
+          `/+++o${c2}oooooooo${c1}oooo/`
+${c2}         ${c1}./${c2}ooosssso++osssssso${c1}+`
+${c2}        .oossssso-````/ossssss+`
+       -osssssso.      :ssssssso.
+      :osssssss/        osssso+++.
+     /ossssssss/        +ssssooo/-
+   `/ossssso+/:-        -:/+osssso+-
+  `+sso+:-`                 `.-/+oso:
+ `++:.                           `-/+/
+ .`                                 `/"##,
+};
+
+const BEDROCK: Logo = Logo {
+    ids: &["bedrock"],
+    colors: &["90", "37"],
+    small: r##"${c1}__
+\ \___
+ \  _ \
+  \___/"##,
+    large: r##"${c1}--------------------------------------
+--------------------------------------
+--------------------------------------
+---${c2}\\\\\\\\\\\\${c1}-----------------------
+----${c2}\\\      \\\${c1}----------------------
+-----${c2}\\\      \\\${c1}---------------------
+------${c2}\\\      \\\\\\\\\\\\\\\\\${c1}------
+-------${c2}\\\                    \\\${c1}-----
+--------${c2}\\\                    \\\${c1}----
+---------${c2}\\\        ______      \\\${c1}---
+----------${c2}\\\                   ///${c1}---
+-----------${c2}\\\                 ///${c1}----
+------------${c2}\\\               ///${c1}-----
+-------------${c2}\\\////////////////${c1}------
+--------------------------------------
+--------------------------------------
+--------------------------------------"##,
+};
+
+const CENTOS: Logo = Logo {
+    ids: &["centos"],
+    colors: &["33", "32", "34", "35"],
+    small: r##"${c2} ____${c1}^${c4}____
+${c2} |\  ${c1}|${c4}  /|
+${c2} | \ ${c1}|${c4} / |
+${c4}<---- ${c3}---->
+${c3} | / ${c2}|${c1} \ |
+${c3} |/__${c2}|${c1}__\|
+${c2}     v"##,
+    large: r##"${c1}                 ..
+               .PLTJ.
+              <><><><>
+     ${c2}KKSSV' 4KKK ${c1}LJ${c4} KKKL.'VSSKK
+     ${c2}KKV' 4KKKKK ${c1}LJ${c4} KKKKAL 'VKK
+     ${c2}V' ' 'VKKKK ${c1}LJ${c4} KKKKV' ' 'V
+     ${c2}.4MA.' 'VKK ${c1}LJ${c4} KKV' '.4Mb.
+${c4}   . ${c2}KKKKKA.' 'V ${c1}LJ${c4} V' '.4KKKKK ${c3}.
+${c4} .4D ${c2}KKKKKKKA.'' ${c1}LJ${c4} ''.4KKKKKKK ${c3}FA.
+${c4}<QDD ++++++++++++  ${c3}++++++++++++ GFD>
+${c4} 'VD ${c3}KKKKKKKK'.. ${c2}LJ ${c1}..'KKKKKKKK ${c3}FV
+${c4}   ' ${c3}VKKKKK'. .4 ${c2}LJ ${c1}K. .'KKKKKV ${c3}'
+     ${c3} 'VK'. .4KK ${c2}LJ ${c1}KKA. .'KV'
+     ${c3}A. . .4KKKK ${c2}LJ ${c1}KKKKA. . .4
+     ${c3}KKA. 'KKKKK ${c2}LJ ${c1}KKKKK' .4KK
+     ${c3}KKSSA. VKKK ${c2}LJ ${c1}KKKV .4SSKK
+${c2}              <><><><>
+               'MTJM'
+                ''"##,
+};
+
+const DEBIAN: Logo = Logo {
+    ids: &["debian"],
+    colors: &["31", "31"],
+    small: r##"${c1}  _____
+ /  __ \
+|  /    |
+|  \___-
+-_
+  --_"##,
+    large: r##"${c2}       _,met$$$$$gg.
+    ,g$$$$$$$$$$$$$$$P.
+  ,g$$P"     """Y$$.".
+ ,$$P'              `$$$.
+',$$P       ,ggs.     `$$b:
+`d$$'     ,$P"'   ${c1}.${c2}    $$$
+ $$P      d$'     ${c1},${c2}    $$P
+ $$:      $$.   ${c1}-${c2}    ,d$$'
+ $$;      Y$b._   _,d$P'
+ Y$$.    ${c1}`.${c2}`"Y$$$$P"'
+${c2} `$$b      ${c1}"-.__
+${c2}  `Y$$
+   `Y$$.
+     `$$b.
+       `Y$$b.
+          `"Y$b._
+              `""""##,
+};
+
+const FEDORA: Logo = Logo {
+    ids: &["fedora"],
+    colors: &["34", "37"],
+    small: r##"${c2}      _____
+     /   __)${c1}\
+${c2}     |  /  ${c1}\ \
+${c1}  ${c2}__${c1}_|  |_${c2}_${c1}/ /
+${c1} / ${c2}(_    _)${c1}_/
+${c1}/ /  ${c2}|  |
+${c1}\ \${c2}__/  |
+${c1} \${c2}(_____/"##,
+    large: r##"${c1}             .',;::::;,'.
+         .';:cccccccccccc:;,.
+      .;cccccccccccccccccccccc;.
+    .:cccccccccccccccccccccccccc:.
+  .;ccccccccccccc;${c2}.:dddl:.${c1};ccccccc;.
+ .:ccccccccccccc;${c2}OWMKOOXMWd${c1};ccccccc:.
+.:ccccccccccccc;${c2}KMMc${c1};cc;${c2}xMMc${c1};ccccccc:.
+,cccccccccccccc;${c2}MMM.${c1};cc;${c2};WW:${c1};cccccccc,
+:cccccccccccccc;${c2}MMM.${c1};cccccccccccccccc:
+:ccccccc;${c2}oxOOOo${c1};${c2}MMM0OOk.${c1};cccccccccccc:
+cccccc;${c2}0MMKxdd:${c1};${c2}MMMkddc.${c1};cccccccccccc;
+ccccc;${c2}XM0'${c1};cccc;${c2}MMM.${c1};cccccccccccccccc'
+ccccc;${c2}MMo${c1};ccccc;${c2}MMW.${c1};ccccccccccccccc;
+ccccc;${c2}0MNc.${c1}ccc${c2}.xMMd${c1};ccccccccccccccc;
+cccccc;${c2}dNMWXXXWM0:${c1};cccccccccccccc:,
+cccccccc;${c2}.:odl:.${c1};cccccccccccccc:,.
+:cccccccccccccccccccccccccccc:'.
+.:cccccccccccccccccccccc:;,..
+  '::cccccccccccccc::;,."##,
+};
+
+const GENTOO: Logo = Logo {
+    ids: &["gentoo"],
+    colors: &["35", "37"],
+    small: r##"${c1} _-----_
+(       \
+\    0   \
+${c2} \        )
+ /      _/
+(     _-
+\____-"##,
+    large: r##"${c1}         -/oyddmdhs+:.
+     -o${c2}dNMMMMMMMMNNmhy+${c1}-`
+   -y${c2}NMMMMMMMMMMMNNNmmdhy${c1}+-
+ `o${c2}mMMMMMMMMMMMMNmdmmmmddhhy${c1}/`
+ om${c2}MMMMMMMMMMMN${c1}hhyyyo${c2}hmdddhhhd${c1}o`
+.y${c2}dMMMMMMMMMMd${c1}hs++so/s${c2}mdddhhhhdm${c1}+`
+ oy${c2}hdmNMMMMMMMN${c1}dyooy${c2}dmddddhhhhyhN${c1}d.
+  :o${c2}yhhdNNMMMMMMMNNNmmdddhhhhhyym${c1}Mh
+    .:${c2}+sydNMMMMMNNNmmmdddhhhhhhmM${c1}my
+       /m${c2}MMMMMMNNNmmmdddhhhhhmMNh${c1}s:
+    `o${c2}NMMMMMMMNNNmmmddddhhdmMNhs${c1}+`
+  `s${c2}NMMMMMMMMNNNmmmdddddmNMmhs${c1}/.
+ /N${c2}MMMMMMMMNNNNmmmdddmNMNdso${c1}:`
++M${c2}MMMMMMNNNNNmmmmdmNMNdso${c1}/-
+yM${c2}MNNNNNNNmmmmmNNMmhs+/${c1}-`
+/h${c2}MMNNNNNNNNMNdhs++/${c1}-`
+`/${c2}ohdmmddhys+++/:${c1}.`
+  `-//////:--."##,
+};
+
+const MANJARO: Logo = Logo {
+    ids: &["manjaro", "manjaro-arm"],
+    colors: &["32"],
+    small: r##"${c1}||||||||| ||||
+||||||||| ||||
+||||      ||||
+|||| |||| ||||
+|||| |||| ||||
+|||| |||| ||||
+|||| |||| ||||"##,
+    large: r##"${c1}##################  ########
+##################  ########
+##################  ########
+##################  ########
+########            ########
+########  ########  ########
+########  ########  ########
+########  ########  ########
+########  ########  ########
+########  ########  ########
+########  ########  ########
+########  ########  ########
+########  ########  ########
+########  ########  ########"##,
+};
+
+const MINT: Logo = Logo {
+    ids: &["linuxmint"],
+    colors: &["32", "37"],
+    small: r##"${c1} ___________
+|_          \
+  | ${c2}| _____ ${c1}|
+  | ${c2}| | | | ${c1}|
+  | ${c2}| | | | ${c1}|
+  | ${c2}\_____/ ${c1}|
+  \_________/"##,
+    large: r##"${c2}MMMMMMMMMMMMMMMMMMMMMMMMMmds+.
+MMm----::-://////////////oymNMd+`
+MMd      ${c1}/++                ${c2}-sNMd:
+MMNso/`  ${c1}dMM    `.::-. .-::.` ${c2}.hMN:
+ddddMMh  ${c1}dMM   :hNMNMNhNMNMNh: ${c2}`NMm
+    NMm  ${c1}dMM  .NMN/-+MMM+-/NMN` ${c2}dMM
+    NMm  ${c1}dMM  -MMm  `MMM   dMM. ${c2}dMM
+    NMm  ${c1}dMM  -MMm  `MMM   dMM. ${c2}dMM
+    NMm  ${c1}dMM  .mmd  `mmm   yMM. ${c2}dMM
+    NMm  ${c1}dMM`  ..`   ...   ydm. ${c2}dMM
+    hMM- ${c1}+MMd/-------...-:sdds  ${c2}dMM
+    -NMm- ${c1}:hNMNNNmdddddddddy/`  ${c2}dMM
+     -dMNs-${c1}``-::::-------.``    ${c2}dMM
+      `/dMNmy+/:-------------:/yMMM
+         ./ydNMMMMMMMMMMMMMMMMMMMMM
+            .MMMMMMMMMMMMMMMMMMM"##,
+};
+
+const NIXOS: Logo = Logo {
+    ids: &["nixos"],
+    colors: &["34", "36"],
+    small: r##"${c1}  \  \ //
+ ==\__\/ //
+   //   \//
+==//     //==
+ //\___//
+// /\  \==
+  // \  \"##,
+    large: r##"${c1}          ::::.    ${c2}':::::     ::::'
+${c1}          '::::    ${c2}':::::.  ::::'
+${c1}            ::::     ${c2}'::::.:::::
+${c1}      .......:::::..... ${c2}::::::::
+${c1}     ::::::::::::::::::. ${c2}::::::    ${c1}::::.
+    ::::::::::::::::::::: ${c2}:::::.  ${c1}.::::'
+${c2}           .....           ::::' ${c1}:::::'
+${c2}          :::::            '::' ${c1}:::::'
+${c2} ........:::::               ' ${c1}:::::::::::.
+${c2}:::::::::::::                 ${c1}:::::::::::::
+${c2} ::::::::::: ${c1}..              :::::
+${c2}     .::::: ${c1}.:::            :::::
+${c2}    .:::::  ${c1}:::::          '''''    ${c2}.....
+    :::::   ${c1}':::::.  ${c2}......:::::::::::::'
+     :::     ${c1}::::::. ${c2}':::::::::::::::::'
+${c1}            .:::::::: ${c2}'::::::::::
+${c1}           .::::''::::.     ${c2}'::::.
+${c1}          .::::'   ::::.     ${c2}'::::.
+${c1}         .::::      ::::      ${c2}'::::."##,
+};
+
+const OPENSUSE: Logo = Logo {
+    ids: &["opensuse", "opensuse-leap", "opensuse-tumbleweed", "suse"],
+    colors: &["32", "37"],
+    small: r##"${c1}  _______
+__|   __ \
+     / .\ \
+     \__/ |
+   _______|
+   \_______
+__________/"##,
+    large: r##"${c2}           .;ldkO0000Okdl;.
+       .;d00xl:^''''''^:ok00d;.
+     .d00l'                'o00d.
+   .d0Kd'${c1}  Okxol:;,.          ${c2}:O0d.
+  .OK${c1}KKK0kOKKKKKKKKKKOxo:,      ${c2}lKO.
+ ,0K${c1}KKKKKKKKKKKKKKK0P^${c2},,,${c1}^dx:${c2}    ;00,
+.OK${c1}KKKKKKKKKKKKKKKk'${c2}.oOPPb.${c1}'0k.${c2}   cKO.
+:KK${c1}KKKKKKKKKKKKKKK: ${c2}kKx..dd ${c1}lKd${c2}   'OK:
+dKK${c1}KKKKKKKKKOx0KKKd ${c2}^0KKKO' ${c1}kKKc${c2}   dKd
+dKK${c1}KKKKKKKKKK;.;oOKx,..${c2}^${c1}..;kKKK0.${c2}  dKd
+:KK${c1}KKKKKKKKKK0o;...^cdxxOK0O/^^'  ${c2}.0K:
+ kKK${c1}KKKKKKKKKKKKK0x;,,......,;od  ${c2}lKk
+ '0K${c1}KKKKKKKKKKKKKKKKKKKK00KKOo^  ${c2}c00'
+  'kK${c1}KKOxddxkOO00000Okxoc;''   ${c2}.dKk'
+    l0Ko.                    .c00l'
+     'l0Kk:.              .;xK0l'
+        'lkK0xl:;,,,,;:ldO0kl'
+            '^:ldxkkkkxdl:^'"##,
+};
+
+const UBUNTU: Logo = Logo {
+    ids: &["ubuntu"],
+    colors: &["31", "37"],
+    small: r##"${c1}         _
+     ---(_)
+ _/  ---  \
+(_) |   |
+  \  --- _/
+     ---(_)"##,
+    large: r##"${c1}            .-/+oossssoo+/-.
+        `:+ssssssssssssssssss+:`
+      -+ssssssssssssssssssyyssss+-
+    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.
+   /sssssssssss${c2}hdmmNNmmyNMMMMh${c1}ssssss/
+  +sssssssss${c2}hmydMMMMMMMNddddy${c1}ssssssss+
+ /ssssssss${c2}hNMMMyhhyyyyhmNMMMNh${c1}ssssssss/
+.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.
++sss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sss+
+ossy${c2}NMMMNyMMh${c1}ssssssssssssss${c2}hmmmh${c1}ssso
+ossy${c2}NMMMNyMMh${c1}ssssssssssssss${c2}hmmmh${c1}ssso
++sss${c2}hhhyNMMNy${c1}ssssssssssss${c2}yNMMMy${c1}sss+
+.ssssssss${c2}dMMMNh${c1}ssssssssss${c2}hNMMMd${c1}ssssssss.
+ /ssssssss${c2}hNMMMyhhyyyyhdNMMMNh${c1}ssssssss/
+  +sssssssss${c2}dmydMMMMMMMMddddy${c1}ssssssss+
+   /sssssssssss${c2}hdmNNNNmyNMMMMh${c1}ssssss/
+    .ossssssssssssssssss${c2}dMMMNy${c1}sssso.
+      -+sssssssssssssssss${c2}yyy${c1}ssss+-
+        `:+ssssssssssssssssss+:`
+            .-/+oossssoo+/-."##,
+};
+
+const VOID: Logo = Logo {
+    ids: &["void"],
+    colors: &["32", "90"],
+    small: r##"${c1}    _______
+ _ \______ -
+| \  ___  \ |
+| | /   \ | |
+| | \___/ | |
+| \______ \_|
+ -_______\"##,
+    large: r##"${c1}                __.;=====;.__
+            _.=+==++=++=+=+===;.
+             -=+++=+===+=+=+++++=_
+        .     -=:``     `--==+=++==.
+       _vi,    `            --+=++++:
+      .uvnvi.       _._       -==+==+.
+     .vvnvnI`    .;==|==;.     :|=||=|.
+${c2}+QmQQm${c1}pvvnv; ${c2}_yYsyQQWUUQQQm #QmQ#${c1}:${c2}QQQWUV$QQm.
+${c2} -QQWQW${c1}pvvo${c2}wZ?.wQQQE${c1}==<${c2}QWWQ/QWQW.QQWW${c1}(: ${c2}jQWQE
+${c2}  -$QQQQmmU'  jQQQ${c1}@${c2}+=<${c2}QWQQ)mQQQ.mQQQC${c1}+;${c2}jWQQ@'
+${c2}   -$WQ8Y${c1}nI:   ${c2}QWQQwgQQWV${c1}`${c2}mWQQ.jQWQQgyyWW@!
+${c1}     -1vvnvv.     `~+++`        ++|+++
+        +vnvnnv,                 `-|===
+         +vnvnvns.           .      :=-
+          -Invnvvnsi..___..=sv=.     `
+            +Invnvnvnnnnnnnnvvnn;.
+              ~|Invnvnvvnvvvnnv}+`
+                 -~|{*l}*|~"##,
+};
+
+const TUX: Logo = Logo {
+    ids: &["linux"],
+    colors: &["37", "90", "33"],
+    small: r##"${c2}    ___
+   (${c1}.. ${c2}|
+   (${c3}<> ${c2}|
+  / ${c1}__  ${c2}\
+ ( ${c1}/  \ ${c2}/|
+${c3}_${c2}/\ ${c1}__)${c2}/${c3}_${c2})
+${c3}\/${c2}-____${c3}\/"##,
+    large: r##"${c2}        #####
+       #######
+       ##${c1}O${c2}#${c1}O${c2}##
+       #${c3}#####${c2}#
+     ##${c1}##${c3}###${c1}##${c2}##
+    #${c1}##########${c2}##
+   #${c1}############${c2}##
+   #${c1}############${c2}###
+  ${c3}##${c2}#${c1}###########${c2}##${c3}#
+${c3}######${c2}#${c1}#######${c2}#${c3}######
+#######${c2}#${c1}#####${c2}#${c3}#######
+  #####${c2}#######${c3}#####"##,
+};
@@ -0,0 +1,230 @@
+use nixinfo::LogoSize;
+use serde_json::{json, Value};
+use std::process::exit;
+
+/// Every field the binary knows about, in the order they are printed.
+const FIELDS: &[&str] = &[
+    "hostname",
+    "distro",
+    "device",
+    "kernel",
+    "uptime",
+    "packages",
+    "environment",
+    "terminal",
+    "cpu",
+    "gpu",
+    "memory",
+    "temps",
+];
+
+const USAGE: &str = "Usage: nixinfo [--json] [--only FIELD,...] [--field FIELD] [--logo SIZE]
+
+Prints a summary of the system.
+
+Options:
+    --json            print the info as JSON
+    --only FIELDS     only gather the comma separated FIELDS
+    --field FIELD     print only the value of FIELD, for use in scripts
+    --logo SIZE       the distro logo next to the summary: large (default), small or none
+    -h, --help        print this help
+
+Fields: hostname, distro, device, kernel, uptime, packages, environment, terminal, cpu, gpu, memory, temps";
+
+/// What the user asked for on the command line.
+struct Args {
+    json: bool,
+    fields: Vec<String>,
+    field: Option<String>,
+    logo: Option<LogoSize>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        json: false,
+        fields: FIELDS.iter().map(|f| f.to_string()).collect(),
+        field: None,
+        logo: Some(LogoSize::Large),
+    };
+    let mut argv = std::env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        match arg.as_str() {
+            "--json" => args.json = true,
+            "--only" => {
+                let only = argv.next().ok_or("--only needs a list of fields")?;
+                args.fields = only.split(',').map(|f| f.trim().to_string()).collect();
+            }
+            "--field" => args.field = Some(argv.next().ok_or("--field needs a field name")?),
+            "--logo" => {
+                args.logo = match argv.next().as_deref() {
+                    Some("large") => Some(LogoSize::Large),
+                    Some("small") => Some(LogoSize::Small),
+                    Some("none") => None,
+                    _ => return Err("--logo needs one of large, small or none".to_string()),
+                }
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                exit(0);
+            }
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        }
+    }
+    for field in args.fields.iter().chain(&args.field) {
+        if !FIELDS.contains(&field.as_str()) {
+            return Err(format!("unknown field `{}`", field));
+        }
+    }
+    Ok(args)
+}
+
+/// Gathers `name`, returning how to print it and its JSON value.
+fn gather(name: &str) -> nixinfo::Result<(String, Value)> {
+    fn plain(value: String) -> (String, Value) {
+        (value.clone(), Value::String(value))
+    }
+    let gathered = match name {
+        "cpu" => plain(nixinfo::cpu()?),
+        "device" => plain(nixinfo::device()?),
+        "distro" => {
+            let distro = nixinfo::distro()?;
+            // On Bedrock, list the other enabled strata as well.
+            let strata: Vec<String> = nixinfo::bedrock_strata()
+                .unwrap_or_default()
+                .iter()
+                .filter(|stratum| stratum.enabled && stratum.name != "bedrock")
+                .map(|stratum| stratum.to_string())
+                .collect();
+            if strata.is_empty() {
+                plain(distro)
+            } else {
+                plain(format!("{} ({})", distro, strata.join(", ")))
+            }
+        }
+        "environment" => plain(nixinfo::environment()?),
+        "gpu" => {
+            let gpus = nixinfo::gpu()?;
+            (gpus.join(", "), json!(gpus))
+        }
+        "hostname" => plain(nixinfo::hostname()?),
+        "kernel" => plain(nixinfo::kernel()?),
+        "memory" => {
+            let memory = nixinfo::memory()?;
+            let display = format!(
+                "{} / {}",
+                nixinfo::MemorySize(memory.used_bytes()),
+                nixinfo::MemorySize(memory.total_bytes)
+            );
+            (display, json!(memory))
+        }
+        "packages" => {
+            let mut counts = serde_json::Map::new();
+            let mut display = Vec::new();
+            for manager in nixinfo::PACKAGE_MANAGERS {
+                if let Ok(count) = nixinfo::packages(manager) {
+                    display.push(format!("{} ({})", count, manager));
+                    counts.insert(manager.to_string(), json!(count));
+                }
+            }
+            (display.join(", "), Value::Object(counts))
+        }
+        "temps" => {
+            let zones = nixinfo::temp()?;
+            let display = zones
+                .iter()
+                .map(|zone| zone.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            (display, json!(zones))
+        }
+        "terminal" => plain(nixinfo::terminal()?),
+        "uptime" => {
+            let uptime = nixinfo::uptime()?;
+            (uptime.to_string(), json!(uptime))
+        }
+        _ => unreachable!("fields are checked in parse_args"),
+    };
+    Ok(gathered)
+}
+
+/// Turns a field name into the label used in the summary.
+fn title(name: &str) -> String {
+    match name {
+        "cpu" | "gpu" => name.to_uppercase(),
+        _ => {
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+    }
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("nixinfo: {}\n\n{}", e, USAGE);
+        exit(2);
+    });
+
+    if let Some(field) = args.field {
+        match gather(&field) {
+            Ok((_, value)) if args.json => println!("{}", value),
+            Ok((display, _)) => println!("{}", display),
+            Err(e) => {
+                eprintln!("nixinfo: {}", e);
+                exit(1);
+            }
+        }
+        return;
+    }
+
+    if args.json {
+        let mut info = serde_json::Map::new();
+        for field in &args.fields {
+            let value = gather(field).map(|(_, value)| value).unwrap_or(Value::Null);
+            info.insert(field.clone(), value);
+        }
+        println!("{}", Value::Object(info));
+        return;
+    }
+
+    let user = nixinfo::env("USER").unwrap_or_default();
+    let hostname = nixinfo::hostname().unwrap_or_default();
+    let header = format!("{}@{}", user, hostname);
+    let mut lines = vec![header.clone(), "-".repeat(header.chars().count())];
+    // The hostname is already part of the header.
+    for field in args.fields.iter().filter(|field| *field != "hostname") {
+        if let Ok((display, _)) = gather(field) {
+            if display.is_empty() {
+                continue;
+            }
+            lines.push(format!("{}: {}", title(field), display));
+        }
+    }
+
+    let size = match args.logo {
+        Some(size) => size,
+        None => {
+            println!("{}", lines.join("\n"));
+            return;
+        }
+    };
+    let logo = nixinfo::logo();
+    let ansi_color = nixinfo::os_release().ok().and_then(|os| os.ansi_color);
+    let art = logo.render_lines(size, ansi_color.as_deref());
+    let widths: Vec<usize> = logo
+        .plain(size)
+        .lines()
+        .map(|line| line.chars().count())
+        .collect();
+    let width = logo.width(size);
+    for i in 0..art.len().max(lines.len()) {
+        let art = art.get(i).map(String::as_str).unwrap_or("");
+        let padding = width - widths.get(i).copied().unwrap_or(0);
+        match lines.get(i) {
+            Some(text) => println!("{}{}   {}", art, " ".repeat(padding), text),
+            None => println!("{}", art),
+        }
+    }
+}
@@ -1,62 +1,395 @@
-use std::fs::{self};
-use std::io::{self, Error};
-
-pub fn memory(mem_value: &str) -> Result<(u64, String), Error> {
-    let no_mem_info_error_msg: &str = &format!("No memoryinfo in {} line!", mem_value);
-    let no_memline_found_error_msg: &str = &format!("No {} line found in /proc/meminfo", mem_value);
-    const MEMINFO: &str = "/proc/meminfo";
-    const UNIT: [&str; 5] = ["kB", "MB", "GB", "TB", "PB"];
-    const SEPARATOR_COLON: &str = ":";
-    const EMPTY_STRING: &str = "";
-
-    pub trait ToIoResult<T> {
-        fn to_io_result(self) -> io::Result<T>;
-    }
-
-    impl<T, E: ToString> ToIoResult<T> for Result<T, E> {
-        fn to_io_result(self) -> io::Result<T> {
-            match self {
-                Ok(x) => Ok(x),
-                Err(err) => Err(io::Error::new(io::ErrorKind::Other, err.to_string())),
-            }
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::size::ByteFormat;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// An amount of memory in bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemorySize(pub u64);
+
+impl MemorySize {
+    /// Formats the size with `format`, e.g. in SI units or with more decimals.
+    pub fn format(&self, format: &ByteFormat) -> String {
+        format.format(self.0)
+    }
+}
+
+impl fmt::Display for MemorySize {
+    /// Formats in IEC units, e.g. "15.4 GiB". The precision sets the decimals, so `{:.2}` gives
+    /// "15.36 GiB".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut format = ByteFormat::new();
+        if let Some(decimals) = f.precision() {
+            format = format.decimals(decimals);
         }
+        write!(f, "{}", format.format(self.0))
     }
+}
+
+/// Physical memory usage, from `/proc/meminfo`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryInfo {
+    pub total_bytes: u64,
+    pub free_bytes: u64,
+    /// Memory available for starting new applications, without swapping.
+    pub available_bytes: u64,
+}
+
+impl MemoryInfo {
+    /// Total memory minus available memory, which counts caches the kernel can drop as unused.
+    pub fn used_bytes(&self) -> u64 {
+        self.total_bytes.saturating_sub(self.available_bytes)
+    }
+}
 
-    let meminfo = fs::read_to_string(MEMINFO)?;
-    for line in meminfo.lines() {
-        if line.starts_with(mem_value) {
-            let mut rsplit = line.rsplit(SEPARATOR_COLON);
-            let size = match rsplit.next() {
-                Some(x) => x
-                    .replace(UNIT[0], EMPTY_STRING)
-                    .trim()
-                    .parse::<u64>()
-                    .to_io_result()?,
-                None => Err(io::Error::new(
-                    io::ErrorKind::Other,
-                    no_memline_found_error_msg,
-                ))?,
+/// The whole of `/proc/meminfo`. Sizes are in bytes, fields the kernel doesn't report are 0.
+///
+/// Fields are named after their key, e.g. `s_reclaimable` for `SReclaimable`. Keys without a
+/// field, such as `DirectMap4k` or ones added by newer kernels, end up in `other`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemInfo {
+    pub mem_total: u64,
+    pub mem_free: u64,
+    /// Only reported since Linux 3.14.
+    pub mem_available: Option<u64>,
+    pub buffers: u64,
+    pub cached: u64,
+    pub swap_cached: u64,
+    pub active: u64,
+    pub inactive: u64,
+    pub active_anon: u64,
+    pub inactive_anon: u64,
+    pub active_file: u64,
+    pub inactive_file: u64,
+    pub unevictable: u64,
+    pub mlocked: u64,
+    pub swap_total: u64,
+    pub swap_free: u64,
+    pub zswap: u64,
+    pub zswapped: u64,
+    pub dirty: u64,
+    pub writeback: u64,
+    pub anon_pages: u64,
+    pub mapped: u64,
+    pub shmem: u64,
+    pub k_reclaimable: u64,
+    pub slab: u64,
+    pub s_reclaimable: u64,
+    pub s_unreclaim: u64,
+    pub kernel_stack: u64,
+    pub page_tables: u64,
+    pub commit_limit: u64,
+    pub committed_as: u64,
+    pub vmalloc_total: u64,
+    pub vmalloc_used: u64,
+    pub anon_huge_pages: u64,
+    pub shmem_huge_pages: u64,
+    pub file_huge_pages: u64,
+    /// A count of pages, not a size.
+    pub huge_pages_total: u64,
+    /// A count of pages, not a size.
+    pub huge_pages_free: u64,
+    /// A count of pages, not a size.
+    pub huge_pages_rsvd: u64,
+    /// A count of pages, not a size.
+    pub huge_pages_surp: u64,
+    pub hugepagesize: u64,
+    pub hugetlb: u64,
+    pub other: BTreeMap<String, u64>,
+}
+
+impl MemInfo {
+    /// Parses the contents of `/proc/meminfo`, `path` is only used in errors.
+    pub fn parse(contents: &str, path: &str) -> Result<MemInfo> {
+        let mut meminfo = MemInfo::default();
+        for (number, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let error = || Error::parse(path, Some(number + 1), "expected `Key: value [kB]`");
+            let (key, value) = line.split_once(':').ok_or_else(error)?;
+            let mut value = value.split_whitespace();
+            let amount: u64 = value
+                .next()
+                .and_then(|amount| amount.parse().ok())
+                .ok_or_else(error)?;
+            let bytes = match value.next() {
+                Some("kB") => amount * 1024,
+                _ => amount,
             };
-            let unit: String = if size <= 999 {
-                "MB".to_string()
-            } else if size >= 1000 {
-                "GB".to_string()
-            } else {
-                "MB".to_string()
+            let field = match key {
+                "MemTotal" => &mut meminfo.mem_total,
+                "MemFree" => &mut meminfo.mem_free,
+                "MemAvailable" => {
+                    meminfo.mem_available = Some(bytes);
+                    continue;
+                }
+                "Buffers" => &mut meminfo.buffers,
+                "Cached" => &mut meminfo.cached,
+                "SwapCached" => &mut meminfo.swap_cached,
+                "Active" => &mut meminfo.active,
+                "Inactive" => &mut meminfo.inactive,
+                "Active(anon)" => &mut meminfo.active_anon,
+                "Inactive(anon)" => &mut meminfo.inactive_anon,
+                "Active(file)" => &mut meminfo.active_file,
+                "Inactive(file)" => &mut meminfo.inactive_file,
+                "Unevictable" => &mut meminfo.unevictable,
+                "Mlocked" => &mut meminfo.mlocked,
+                "SwapTotal" => &mut meminfo.swap_total,
+                "SwapFree" => &mut meminfo.swap_free,
+                "Zswap" => &mut meminfo.zswap,
+                "Zswapped" => &mut meminfo.zswapped,
+                "Dirty" => &mut meminfo.dirty,
+                "Writeback" => &mut meminfo.writeback,
+                "AnonPages" => &mut meminfo.anon_pages,
+                "Mapped" => &mut meminfo.mapped,
+                "Shmem" => &mut meminfo.shmem,
+                "KReclaimable" => &mut meminfo.k_reclaimable,
+                "Slab" => &mut meminfo.slab,
+                "SReclaimable" => &mut meminfo.s_reclaimable,
+                "SUnreclaim" => &mut meminfo.s_unreclaim,
+                "KernelStack" => &mut meminfo.kernel_stack,
+                "PageTables" => &mut meminfo.page_tables,
+                "CommitLimit" => &mut meminfo.commit_limit,
+                "Committed_AS" => &mut meminfo.committed_as,
+                "VmallocTotal" => &mut meminfo.vmalloc_total,
+                "VmallocUsed" => &mut meminfo.vmalloc_used,
+                "AnonHugePages" => &mut meminfo.anon_huge_pages,
+                "ShmemHugePages" => &mut meminfo.shmem_huge_pages,
+                "FileHugePages" => &mut meminfo.file_huge_pages,
+                "HugePages_Total" => &mut meminfo.huge_pages_total,
+                "HugePages_Free" => &mut meminfo.huge_pages_free,
+                "HugePages_Rsvd" => &mut meminfo.huge_pages_rsvd,
+                "HugePages_Surp" => &mut meminfo.huge_pages_surp,
+                "Hugepagesize" => &mut meminfo.hugepagesize,
+                "Hugetlb" => &mut meminfo.hugetlb,
+                _ => meminfo.other.entry(key.to_string()).or_default(),
             };
+            *field = bytes;
+        }
+        Ok(meminfo)
+    }
+
+    /// `MemAvailable`, or an estimate from free memory, buffers and page cache on kernels too old
+    /// to report it.
+    pub fn available(&self) -> u64 {
+        self.mem_available
+            .unwrap_or(self.mem_free + self.buffers + self.cached)
+    }
+
+    /// Total memory minus available memory, as `free` reports it.
+    pub fn used(&self) -> u64 {
+        self.mem_total.saturating_sub(self.available())
+    }
+
+    /// Total memory minus free memory, buffers and page cache, as htop used to report it.
+    pub fn used_htop(&self) -> u64 {
+        self.mem_total
+            .saturating_sub(self.mem_free)
+            .saturating_sub(self.buffers)
+            .saturating_sub(self.cached)
+    }
+
+    pub fn swap_used(&self) -> u64 {
+        self.swap_total.saturating_sub(self.swap_free)
+    }
+}
+
+pub fn meminfo(probe: &Probe) -> Result<MemInfo> {
+    let path = probe.path("/proc/meminfo");
+    let contents = crate::shared_functions::read(&path)?;
+    MemInfo::parse(&contents, &path.display().to_string())
+}
+
+/// A swap partition or file, from `/proc/swaps`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapDevice {
+    /// e.g. "/dev/nvme0n1p3", "/swapfile" or "/dev/zram0".
+    pub path: String,
+    /// "partition" or "file".
+    pub kind: String,
+    /// In bytes.
+    pub size: u64,
+    /// In bytes.
+    pub used: u64,
+    /// Higher priorities are used first.
+    pub priority: i32,
+}
+
+/// A compressed RAM disk from `/sys/block/zram*`, usually used as swap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZramDevice {
+    /// e.g. "zram0".
+    pub name: String,
+    /// The uncompressed size of the disk in bytes.
+    pub disksize: u64,
+    /// The selected compression algorithm, e.g. "zstd".
+    pub algorithm: Option<String>,
+    /// Bytes stored, before compression.
+    pub orig_data_size: u64,
+    /// Bytes stored, after compression.
+    pub compr_data_size: u64,
+    /// Memory used including the allocator's overhead, in bytes.
+    pub mem_used_total: u64,
+}
+
+impl ZramDevice {
+    /// How many times smaller the data got, `None` while the device is empty.
+    pub fn ratio(&self) -> Option<f64> {
+        if self.compr_data_size == 0 {
+            None
+        } else {
+            Some(self.orig_data_size as f64 / self.compr_data_size as f64)
+        }
+    }
+}
 
-            return Ok((size, unit));
+/// The parameters of zswap, the compressed cache in front of swap, from
+/// `/sys/module/zswap/parameters`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Zswap {
+    pub enabled: bool,
+    /// e.g. "lzo" or "zstd".
+    pub compressor: Option<String>,
+    /// e.g. "zbud" or "zsmalloc", gone since Linux 6.15.
+    pub zpool: Option<String>,
+    /// The most memory the pool may take, in percent of RAM.
+    pub max_pool_percent: Option<u32>,
+    pub accept_threshold_percent: Option<u32>,
+    pub shrinker_enabled: Option<bool>,
+}
+
+/// Undoes the octal escapes /proc uses for whitespace in paths, e.g. `\040` for a space.
+fn unescape(path: &str) -> String {
+    let mut unescaped = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(start) = rest.find('\\') {
+        unescaped.push_str(&rest[..start]);
+        let code = rest
+            .get(start + 1..start + 4)
+            .and_then(|code| u8::from_str_radix(code, 8).ok());
+        match code {
+            Some(code) => {
+                unescaped.push(code as char);
+                rest = &rest[start + 4..];
+            }
+            None => {
+                unescaped.push('\\');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+pub fn swaps(probe: &Probe) -> Result<Vec<SwapDevice>> {
+    let path = probe.path("/proc/swaps");
+    let contents = crate::shared_functions::read(&path)?;
+    let mut swaps = Vec::new();
+    // The first line is the header.
+    for (number, line) in contents.lines().enumerate().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
         }
+        let error = || {
+            Error::parse(
+                path.display(),
+                Some(number + 1),
+                "expected `Filename Type Size Used Priority`",
+            )
+        };
+        let number = |i: usize| {
+            fields
+                .get(i)
+                .ok_or_else(error)?
+                .parse::<i64>()
+                .map_err(|_| error())
+        };
+        swaps.push(SwapDevice {
+            path: unescape(fields[0]),
+            kind: fields.get(1).ok_or_else(error)?.to_string(),
+            // Sizes are in kB.
+            size: number(2)? as u64 * 1024,
+            used: number(3)? as u64 * 1024,
+            priority: number(4)? as i32,
+        });
+    }
+    Ok(swaps)
+}
+
+pub fn zram(probe: &Probe) -> Result<Vec<ZramDevice>> {
+    let read = |path: &std::path::Path| {
+        crate::shared_functions::read(path)
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+    let number = |path: &std::path::Path| read(path).and_then(|value| value.parse::<u64>().ok());
+    let mut devices = Vec::new();
+    for path in crate::shared_functions::glob(probe.path("/sys/block/zram[0-9]*"))? {
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        // "lzo lzo-rle [zstd]", with the algorithm in use in brackets.
+        let algorithm = read(&path.join("comp_algorithm")).and_then(|algorithms| {
+            algorithms
+                .split_whitespace()
+                .find_map(|algorithm| algorithm.strip_prefix('[')?.strip_suffix(']'))
+                .map(String::from)
+        });
+        // `mm_stat` replaced the separate files in Linux 4.11.
+        let mm_stat: Vec<u64> = read(&path.join("mm_stat"))
+            .map(|stat| {
+                stat.split_whitespace()
+                    .filter_map(|value| value.parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let stat = |i: usize, file: &str| {
+            mm_stat
+                .get(i)
+                .copied()
+                .or_else(|| number(&path.join(file)))
+                .unwrap_or(0)
+        };
+        devices.push(ZramDevice {
+            name,
+            disksize: number(&path.join("disksize")).unwrap_or(0),
+            algorithm,
+            orig_data_size: stat(0, "orig_data_size"),
+            compr_data_size: stat(1, "compr_data_size"),
+            mem_used_total: stat(2, "mem_used_total"),
+        });
     }
-    Err(io::Error::new(io::ErrorKind::Other, no_mem_info_error_msg))?
+    devices.sort_by(|a, b| a.name.len().cmp(&b.name.len()).then(a.name.cmp(&b.name)));
+    Ok(devices)
 }
 
-pub fn memory_formatter(mem_result: u64, unit: String) -> Result<String, Error> {
-    let final_result = match unit.as_str() {
-        "MB" => mem_result / 1000,
-        "GB" => mem_result / (1000*1000),
-        _ => mem_result / 1000
+pub fn zswap(probe: &Probe) -> Result<Zswap> {
+    let dir = probe.path("/sys/module/zswap/parameters");
+    let read = |file: &str| {
+        crate::shared_functions::read(dir.join(file)).map(|value| value.trim().to_string())
     };
-    let output = format!("{} {}", final_result, unit);
-    Ok(output)
+    let flag = |value: String| value == "Y" || value == "1";
+    Ok(Zswap {
+        enabled: flag(read("enabled")?),
+        compressor: read("compressor").ok(),
+        zpool: read("zpool").ok(),
+        max_pool_percent: read("max_pool_percent")
+            .ok()
+            .and_then(|percent| percent.parse().ok()),
+        accept_threshold_percent: read("accept_threshold_percent")
+            .ok()
+            .and_then(|percent| percent.parse().ok()),
+        shrinker_enabled: read("shrinker_enabled").ok().map(flag),
+    })
 }
@@ -1,4 +1,44 @@
+use std::fmt;
+
+/// The package managers `packages()` knows how to count.
+pub const PACKAGE_MANAGERS: &[&str] = &[
+    "apk", "dpkg", "dnf", "eopkg", "flatpak", "pacman", "pip", "portage", "rpm", "xbps",
+];
+
+/// The number of packages installed by a package manager.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackageCount {
+    /// Packages the user asked for, if the manager keeps track of that.
+    pub explicit: Option<u64>,
+    pub total: u64,
+}
+
+impl PackageCount {
+    pub(crate) fn total(total: usize) -> PackageCount {
+        PackageCount {
+            explicit: None,
+            total: total as u64,
+        }
+    }
+}
+
+impl fmt::Display for PackageCount {
+    /// Formats as "N" or "N (explicit), M (total)".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.explicit {
+            Some(explicit) => write!(f, "{} (explicit), {} (total)", explicit, self.total),
+            None => write!(f, "{}", self.total),
+        }
+    }
+}
+
 pub fn count(output: std::process::Output) -> usize {
     // -1 to deal with newline at end of output
-    output.stdout.iter().filter(|&&i| i == b'\n').count() - 1
+    output
+        .stdout
+        .iter()
+        .filter(|&&i| i == b'\n')
+        .count()
+        .saturating_sub(1)
 }
@@ -0,0 +1,67 @@
+use crate::probe::Probe;
+use crate::shared_functions::read;
+
+/// The kind of system being probed, which decides where some info is read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Platform {
+    Linux,
+    /// Android, including Termux.
+    Android,
+    /// Windows Subsystem for Linux.
+    Wsl,
+    /// A Linux container such as Docker, Podman, LXC or a Kubernetes pod.
+    Container,
+}
+
+/// Detects the platform using only file reads, so it costs nothing to call it before every getter.
+pub fn detect(probe: &Probe) -> Platform {
+    let exists = |path: &str| probe.path(path).exists();
+    if exists("/system/build.prop") || exists("/system/bin/getprop") {
+        return Platform::Android;
+    }
+
+    let osrelease = read(probe.path("/proc/sys/kernel/osrelease"))
+        .unwrap_or_default()
+        .to_lowercase();
+    if osrelease.contains("microsoft") || exists("/proc/sys/fs/binfmt_misc/WSLInterop") {
+        return Platform::Wsl;
+    }
+
+    if exists("/run/systemd/container") || runtime(probe).is_some() {
+        return Platform::Container;
+    }
+
+    Platform::Linux
+}
+
+/// The container runtime the probed system runs under, e.g. "Docker", "Podman" or "LXC".
+pub fn runtime(probe: &Probe) -> Option<&'static str> {
+    let exists = |path: &str| probe.path(path).exists();
+    if exists("/run/.containerenv") {
+        return Some("Podman");
+    }
+    if exists("/.dockerenv") {
+        return Some("Docker");
+    }
+    // systemd's container interface, which most runtimes follow.
+    let container = read(probe.path("/run/systemd/container")).unwrap_or_default();
+    match container.trim() {
+        "docker" => return Some("Docker"),
+        "podman" => return Some("Podman"),
+        "lxc" | "lxc-libvirt" => return Some("LXC"),
+        "systemd-nspawn" => return Some("systemd-nspawn"),
+        _ => {}
+    }
+    let init_cgroup = read(probe.path("/proc/1/cgroup")).unwrap_or_default();
+    [
+        ("kubepods", "Kubernetes"),
+        ("docker", "Docker"),
+        ("libpod", "Podman"),
+        ("lxc", "LXC"),
+        ("containerd", "containerd"),
+    ]
+    .iter()
+    .find(|(name, _)| init_cgroup.contains(name))
+    .map(|(_, runtime)| *runtime)
+}
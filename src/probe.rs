@@ -0,0 +1,99 @@
+use crate::error::{Error, Result};
+use crate::platform::{self, Platform};
+use crate::shared_functions;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use std::sync::OnceLock;
+
+/// Where nixinfo looks for the files it reads.
+///
+/// The default probe reads the running system. Pointing it at another root (a mounted container
+/// image, a chroot or a captured fixture tree) makes every getter read from there instead, e.g.
+/// `Probe::with_root("/mnt/image").kernel()` reads `/mnt/image/proc/sys/kernel/osrelease`.
+#[derive(Clone, Debug)]
+pub struct Probe {
+    root: PathBuf,
+    proc: PathBuf,
+    sys: PathBuf,
+    platform: OnceLock<Platform>,
+}
+
+impl Probe {
+    /// A probe for the running system, rooted at `/`.
+    pub fn new() -> Probe {
+        Probe::with_root("/")
+    }
+
+    /// A probe rooted at `root`, with `proc` and `sys` inside of it.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Probe {
+        let root = root.into();
+        Probe {
+            proc: root.join("proc"),
+            sys: root.join("sys"),
+            root,
+            platform: OnceLock::new(),
+        }
+    }
+
+    /// Reads what would normally be `/proc` from `proc` instead.
+    pub fn proc_dir<P: Into<PathBuf>>(mut self, proc: P) -> Probe {
+        self.proc = proc.into();
+        self.platform = OnceLock::new();
+        self
+    }
+
+    /// Reads what would normally be `/sys` from `sys` instead.
+    pub fn sys_dir<P: Into<PathBuf>>(mut self, sys: P) -> Probe {
+        self.sys = sys.into();
+        self.platform = OnceLock::new();
+        self
+    }
+
+    /// The root directory of this probe.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Runs a command of the probed system. Commands can only see the running system, so this
+    /// fails with `Unsupported` for a probe rooted anywhere but `/`.
+    pub(crate) fn run(&self, command: &str, args: &[&str]) -> Result<Output> {
+        if self.root != Path::new("/") {
+            return Err(Error::Unsupported(format!(
+                "running {} for a probe rooted at {}",
+                command,
+                self.root.display()
+            )));
+        }
+        shared_functions::run(command, args)
+    }
+
+    /// The platform of the probed system, detected on first use and cached.
+    pub fn platform(&self) -> Platform {
+        *self.platform.get_or_init(|| platform::detect(self))
+    }
+
+    /// Maps an absolute path on the probed system to where it can be read from.
+    pub(crate) fn path<P: AsRef<Path>>(&self, absolute: P) -> PathBuf {
+        let absolute = absolute.as_ref();
+        if let Ok(rest) = absolute.strip_prefix("/proc") {
+            self.proc.join(rest)
+        } else if let Ok(rest) = absolute.strip_prefix("/sys") {
+            self.sys.join(rest)
+        } else {
+            self.root
+                .join(absolute.strip_prefix("/").unwrap_or(absolute))
+        }
+    }
+}
+
+/// The probe used by the free functions, shared so the platform is only detected once.
+pub(crate) fn system() -> &'static Probe {
+    static SYSTEM: OnceLock<Probe> = OnceLock::new();
+    SYSTEM.get_or_init(Probe::new)
+}
+
+impl Default for Probe {
+    fn default() -> Probe {
+        Probe::new()
+    }
+}
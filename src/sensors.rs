@@ -0,0 +1,173 @@
+use crate::error::Result;
+use crate::probe::Probe;
+use crate::shared_functions::{glob, read};
+use std::fmt;
+use std::path::Path;
+
+/// What a hwmon sensor measures, and so the unit of its values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SensorKind {
+    /// In degrees Celsius.
+    Temperature,
+    /// In RPM.
+    Fan,
+    /// In volts.
+    Voltage,
+    /// In amperes.
+    Current,
+    /// In watts.
+    Power,
+}
+
+impl SensorKind {
+    const ALL: [SensorKind; 5] = [
+        SensorKind::Temperature,
+        SensorKind::Fan,
+        SensorKind::Voltage,
+        SensorKind::Current,
+        SensorKind::Power,
+    ];
+
+    fn prefix(self) -> &'static str {
+        match self {
+            SensorKind::Temperature => "temp",
+            SensorKind::Fan => "fan",
+            SensorKind::Voltage => "in",
+            SensorKind::Current => "curr",
+            SensorKind::Power => "power",
+        }
+    }
+
+    /// The kernel reports millidegrees, RPM, millivolts, milliamperes and microwatts.
+    fn divisor(self) -> f64 {
+        match self {
+            SensorKind::Fan => 1.0,
+            SensorKind::Power => 1_000_000.0,
+            _ => 1000.0,
+        }
+    }
+
+    /// The unit values are given in, e.g. "°C" or "RPM".
+    pub fn unit(self) -> &'static str {
+        match self {
+            SensorKind::Temperature => "°C",
+            SensorKind::Fan => "RPM",
+            SensorKind::Voltage => "V",
+            SensorKind::Current => "A",
+            SensorKind::Power => "W",
+        }
+    }
+}
+
+/// One input of a hwmon chip, such as `temp1` or `fan2`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sensor {
+    pub kind: SensorKind,
+    /// The contents of the `_label` file, e.g. "Tctl" or "Package id 0", or the input's name
+    /// such as "temp1" if there is none.
+    pub label: String,
+    /// The current reading, `None` if the chip couldn't be read.
+    pub input: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub crit: Option<f64>,
+}
+
+impl fmt::Display for Sensor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.input {
+            Some(input) => write!(f, "{}: {} {}", self.label, input, self.kind.unit()),
+            None => write!(f, "{}: N/A", self.label),
+        }
+    }
+}
+
+/// A hwmon chip from `/sys/class/hwmon`, e.g. k10temp, coretemp, nvme or amdgpu.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorChip {
+    /// The driver's name for the chip, from the `name` file.
+    pub name: String,
+    /// The N in `hwmonN`.
+    pub index: u32,
+    pub sensors: Vec<Sensor>,
+}
+
+fn value(dir: &Path, file: &str, kind: SensorKind) -> Option<f64> {
+    let value: f64 = read(dir.join(file)).ok()?.trim().parse().ok()?;
+    Some(value / kind.divisor())
+}
+
+/// Collects the sensors in `dir`, ordered by kind and then by number.
+fn read_sensors(dir: &Path) -> Result<Vec<Sensor>> {
+    let mut sensors = Vec::new();
+    for kind in SensorKind::ALL {
+        let mut numbers: Vec<u32> = Vec::new();
+        // Some power meters only report an average.
+        for suffix in ["_input", "_average"] {
+            for path in glob(dir.join(format!("{}[0-9]*{}", kind.prefix(), suffix)))? {
+                let number = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_prefix(kind.prefix()))
+                    .and_then(|name| name.strip_suffix(suffix))
+                    .and_then(|number| number.parse().ok());
+                if let Some(number) = number {
+                    if !numbers.contains(&number) {
+                        numbers.push(number);
+                    }
+                }
+            }
+        }
+        numbers.sort_unstable();
+        for number in numbers {
+            let name = format!("{}{}", kind.prefix(), number);
+            let label = read(dir.join(format!("{}_label", name)))
+                .map(|label| label.trim().to_string())
+                .unwrap_or_else(|_| name.clone());
+            let get = |suffix: &str| value(dir, &format!("{}_{}", name, suffix), kind);
+            sensors.push(Sensor {
+                kind,
+                label,
+                input: get("input").or_else(|| get("average")),
+                min: get("min"),
+                max: get("max"),
+                crit: get("crit"),
+            });
+        }
+    }
+    Ok(sensors)
+}
+
+pub fn chips(probe: &Probe) -> Result<Vec<SensorChip>> {
+    let mut chips = Vec::new();
+    for path in glob(probe.path("/sys/class/hwmon/hwmon[0-9]*"))? {
+        let index = match path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("hwmon"))
+            .and_then(|index| index.parse().ok())
+        {
+            Some(index) => index,
+            None => continue,
+        };
+        let mut sensors = read_sensors(&path)?;
+        // Drivers written before Linux 3.x keep their files in the device directory instead.
+        if sensors.is_empty() {
+            sensors = read_sensors(&path.join("device"))?;
+        }
+        let name = read(path.join("name"))
+            .or_else(|_| read(path.join("device/name")))
+            .map(|name| name.trim().to_string())
+            .unwrap_or_else(|_| format!("hwmon{}", index));
+        chips.push(SensorChip {
+            name,
+            index,
+            sensors,
+        });
+    }
+    chips.sort_by_key(|chip| chip.index);
+    Ok(chips)
+}
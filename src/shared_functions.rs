@@ -1,24 +1,63 @@
-use std::fs::File;
-use std::io::Error;
-use std::io::{BufReader, Read};
+use crate::error::{Error, Result};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
 
-/// Returns the exit code of `which getprop > /dev/null 2>&1"`
-pub fn exit_code() -> i32 {
-    let status = std::process::Command::new("sh")
-        .args(["-c", "which getprop > /dev/null 2>&1"])
-        .status()
-        .expect("");
-    status.code().unwrap()
+/// Reads a file to a string, attaching the path to any error.
+pub fn read<P: AsRef<Path>>(path: P) -> Result<String> {
+    let path = path.as_ref();
+    std::fs::read_to_string(path).map_err(|e| Error::io(e, path.display()))
 }
 
-pub fn read(file: File) -> Result<String, Error> {
-    let mut buf_reader = BufReader::new(file);
-    let mut contents = String::new();
-    buf_reader.read_to_string(&mut contents)?;
-    Ok(contents)
+/// Parses a kernel CPU list such as "0-3,8,10-11", as used throughout sysfs and cgroups. An empty
+/// list, like `/sys/devices/system/cpu/offline` on most systems, gives no CPUs.
+pub fn cpu_list(list: &str, path: &str) -> Result<Vec<u32>> {
+    let mut cpus = Vec::new();
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        let number = |n: &str| {
+            n.trim()
+                .parse::<u32>()
+                .map_err(|_| Error::parse(path, None, format!("invalid CPU list `{}`", list.trim())))
+        };
+        match range.split_once('-') {
+            Some((start, end)) => cpus.extend(number(start)?..=number(end)?),
+            None => cpus.push(number(range)?),
+        }
+    }
+    Ok(cpus)
 }
 
-pub fn line(file: File, line: usize) -> String {
-    let contents = read(file).unwrap();
-    contents.split('\n').collect::<Vec<&str>>()[line].to_string()
+/// Collects the paths matching a glob pattern.
+pub fn glob<P: AsRef<Path>>(pattern: P) -> Result<Vec<PathBuf>> {
+    let pattern = pattern.as_ref().to_string_lossy();
+    let paths = glob::glob(&pattern).map_err(|e| Error::parse(&pattern, None, e.msg))?;
+    paths
+        .map(|entry| {
+            entry.map_err(|e| {
+                let path = e.path().display().to_string();
+                Error::io(e.into(), path)
+            })
+        })
+        .collect()
+}
+
+/// Runs a command, failing if it can't be spawned or exits unsuccessfully.
+pub fn run(command: &str, args: &[&str]) -> Result<Output> {
+    let output = Command::new(command)
+        .args(args)
+        .output()
+        .map_err(|e| Error::io(e, command))?;
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(Error::CommandFailed {
+            command: [command].iter().chain(args).copied().collect::<Vec<_>>().join(" "),
+            status: output.status.code(),
+        })
+    }
+}
+
+/// Runs `sh -c command` and returns its trimmed stdout.
+pub fn shell(command: &str) -> Result<String> {
+    let output = run("sh", &["-c", command])?;
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
@@ -0,0 +1,88 @@
+/// Which units sizes are shown in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ByteUnits {
+    /// Powers of 1024: KiB, MiB, GiB and so on, as used by `free -h` and htop.
+    Iec,
+    /// Powers of 1000: kB, MB, GB and so on, as used by disk vendors.
+    Si,
+}
+
+impl ByteUnits {
+    fn base(self) -> f64 {
+        match self {
+            ByteUnits::Iec => 1024.0,
+            ByteUnits::Si => 1000.0,
+        }
+    }
+
+    fn names(self) -> [&'static str; 7] {
+        match self {
+            ByteUnits::Iec => ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            ByteUnits::Si => ["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+        }
+    }
+}
+
+/// How to turn a number of bytes into text. The largest unit that keeps the number at 1 or above
+/// is picked, e.g. 512 MiB stays "512.0 MiB" rather than "0.5 GiB".
+///
+/// `ByteFormat::new().units(ByteUnits::Si).decimals(2).format(16_496_934_912)` gives "16.50 GB".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ByteFormat {
+    units: ByteUnits,
+    decimals: usize,
+}
+
+impl ByteFormat {
+    /// IEC units with one decimal.
+    pub fn new() -> ByteFormat {
+        ByteFormat {
+            units: ByteUnits::Iec,
+            decimals: 1,
+        }
+    }
+
+    pub fn units(mut self, units: ByteUnits) -> ByteFormat {
+        self.units = units;
+        self
+    }
+
+    /// How many decimals to show, plain bytes never have any.
+    pub fn decimals(mut self, decimals: usize) -> ByteFormat {
+        self.decimals = decimals;
+        self
+    }
+
+    pub fn format(&self, bytes: u64) -> String {
+        let base = self.units.base();
+        let names = self.units.names();
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        // Compare what will be shown rather than the exact value, so 1_048_575 bytes is "1.0 MiB"
+        // and not "1024.0 KiB". Plain bytes are shown without decimals.
+        let scale = 10f64.powi(self.decimals as i32);
+        let shown = |value: f64, unit: usize| {
+            if unit == 0 {
+                value
+            } else {
+                (value * scale).round() / scale
+            }
+        };
+        while shown(value, unit) >= base && unit < names.len() - 1 {
+            value /= base;
+            unit += 1;
+        }
+        if unit == 0 {
+            format!("{} {}", bytes, names[0])
+        } else {
+            format!("{:.*} {}", self.decimals, value, names[unit])
+        }
+    }
+}
+
+impl Default for ByteFormat {
+    fn default() -> ByteFormat {
+        ByteFormat::new()
+    }
+}
@@ -0,0 +1,208 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use std::convert::TryInto;
+
+const ENTRY_POINT: &str = "/sys/firmware/dmi/tables/smbios_entry_point";
+const TABLE: &str = "/sys/firmware/dmi/tables/DMI";
+
+const TYPE_MEMORY_DEVICE: u8 = 17;
+const TYPE_END_OF_TABLE: u8 = 127;
+
+/// Names of the Memory Type field of type 17, from the SMBIOS spec (DSP0134 7.18.2).
+const MEMORY_TYPES: &[(u8, &str)] = &[
+    (0x01, "Other"),
+    (0x02, "Unknown"),
+    (0x03, "DRAM"),
+    (0x04, "EDRAM"),
+    (0x05, "VRAM"),
+    (0x06, "SRAM"),
+    (0x07, "RAM"),
+    (0x08, "ROM"),
+    (0x09, "Flash"),
+    (0x0a, "EEPROM"),
+    (0x0b, "FEPROM"),
+    (0x0c, "EPROM"),
+    (0x0d, "CDRAM"),
+    (0x0e, "3DRAM"),
+    (0x0f, "SDRAM"),
+    (0x10, "SGRAM"),
+    (0x11, "RDRAM"),
+    (0x12, "DDR"),
+    (0x13, "DDR2"),
+    (0x14, "DDR2 FB-DIMM"),
+    (0x18, "DDR3"),
+    (0x19, "FBD2"),
+    (0x1a, "DDR4"),
+    (0x1b, "LPDDR"),
+    (0x1c, "LPDDR2"),
+    (0x1d, "LPDDR3"),
+    (0x1e, "LPDDR4"),
+    (0x1f, "Logical non-volatile device"),
+    (0x20, "HBM"),
+    (0x21, "HBM2"),
+    (0x22, "DDR5"),
+    (0x23, "LPDDR5"),
+    (0x24, "HBM3"),
+];
+
+/// An installed memory module, from an SMBIOS type 17 (Memory Device) structure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryDevice {
+    /// The slot, e.g. "DIMM_A1" or "ChannelA-DIMM0".
+    pub locator: Option<String>,
+    /// e.g. "BANK 0" or "P0 CHANNEL A".
+    pub bank_locator: Option<String>,
+    /// In bytes, `None` if the firmware doesn't know.
+    pub size: Option<u64>,
+    /// e.g. "DDR4" or "LPDDR5".
+    pub memory_type: Option<String>,
+    /// The fastest speed the module supports, in MT/s.
+    pub speed: Option<u32>,
+    /// The speed the module is running at, in MT/s.
+    pub configured_speed: Option<u32>,
+    pub manufacturer: Option<String>,
+    pub part_number: Option<String>,
+    pub serial_number: Option<String>,
+}
+
+/// One structure of the SMBIOS table: its formatted area and its strings.
+struct Structure<'a> {
+    data: &'a [u8],
+    strings: Vec<&'a [u8]>,
+}
+
+impl Structure<'_> {
+    fn u8(&self, offset: usize) -> Option<u8> {
+        self.data.get(offset).copied()
+    }
+
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let bytes = self.data.get(offset..offset + 2)?.try_into().ok()?;
+        Some(u16::from_le_bytes(bytes))
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let bytes = self.data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(u32::from_le_bytes(bytes))
+    }
+
+    /// Looks up the string whose 1-based index is stored at `offset`, 0 means there is none.
+    fn string(&self, offset: usize) -> Option<String> {
+        let index = self.u8(offset)? as usize;
+        let string = self.strings.get(index.checked_sub(1)?)?;
+        let string = String::from_utf8_lossy(string).trim().to_string();
+        if string.is_empty() {
+            None
+        } else {
+            Some(string)
+        }
+    }
+}
+
+/// The length of the structure table according to the entry point, for both the 32-bit (`_SM_`)
+/// and 64-bit (`_SM3_`) formats.
+fn table_length(entry_point: &[u8]) -> Option<usize> {
+    if entry_point.starts_with(b"_SM3_") {
+        let bytes = entry_point.get(0x0c..0x10)?.try_into().ok()?;
+        Some(u32::from_le_bytes(bytes) as usize)
+    } else if entry_point.starts_with(b"_SM_") {
+        let bytes = entry_point.get(0x16..0x18)?.try_into().ok()?;
+        Some(u16::from_le_bytes(bytes) as usize)
+    } else {
+        None
+    }
+}
+
+/// Splits the table into its structures, stopping at the end-of-table structure.
+fn structures(table: &[u8]) -> Vec<(u8, Structure<'_>)> {
+    let mut structures = Vec::new();
+    let mut offset = 0;
+    while let (Some(&kind), Some(&length)) = (table.get(offset), table.get(offset + 1)) {
+        let length = length as usize;
+        let data = match table.get(offset..offset + length) {
+            Some(data) if length >= 4 => data,
+            _ => break,
+        };
+        // The strings follow the formatted area, each NUL terminated, with an extra NUL at the
+        // end. A structure without strings is followed by two NULs.
+        let mut end = offset + length;
+        let mut strings = Vec::new();
+        loop {
+            let rest = match table.get(end..) {
+                Some(rest) if !rest.is_empty() => rest,
+                _ => break,
+            };
+            let nul = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+            if nul == 0 {
+                end += 1;
+                break;
+            }
+            strings.push(&rest[..nul]);
+            end += nul + 1;
+        }
+        if strings.is_empty() {
+            end += 1;
+        }
+        structures.push((kind, Structure { data, strings }));
+        if kind == TYPE_END_OF_TABLE {
+            break;
+        }
+        offset = end;
+    }
+    structures
+}
+
+fn memory_device(structure: &Structure) -> Option<MemoryDevice> {
+    // 0 means the slot is empty, 0xffff that the size is unknown, and 0x7fff that it's in the
+    // Extended Size field. Bit 15 picks KiB instead of MiB.
+    let size = match structure.u16(0x0c)? {
+        0 => return None,
+        0xffff => None,
+        0x7fff => structure
+            .u32(0x1c)
+            .map(|mib| (mib & 0x7fff_ffff) as u64 * 1024 * 1024),
+        size if size & 0x8000 != 0 => Some((size & 0x7fff) as u64 * 1024),
+        size => Some(size as u64 * 1024 * 1024),
+    };
+    let memory_type = structure.u8(0x12).and_then(|kind| {
+        MEMORY_TYPES
+            .iter()
+            .find(|(id, _)| *id == kind)
+            .map(|(_, name)| name.to_string())
+    });
+    // A speed of 0xffff means it's in the extended speed field, which SMBIOS 3.3 added.
+    let speed = |offset: usize, extended: usize| match structure.u16(offset)? {
+        0 => None,
+        0xffff => structure.u32(extended).filter(|speed| *speed != 0),
+        speed => Some(speed as u32),
+    };
+    Some(MemoryDevice {
+        locator: structure.string(0x10),
+        bank_locator: structure.string(0x11),
+        size,
+        memory_type,
+        speed: speed(0x15, 0x54),
+        configured_speed: speed(0x20, 0x58),
+        manufacturer: structure.string(0x17),
+        part_number: structure.string(0x1a),
+        serial_number: structure.string(0x18),
+    })
+}
+
+pub fn memory_devices(probe: &Probe) -> Result<Vec<MemoryDevice>> {
+    let path = probe.path(TABLE);
+    // Both files are only readable by root.
+    let mut table = std::fs::read(&path).map_err(|e| Error::io(e, path.display()))?;
+    if let Some(length) = std::fs::read(probe.path(ENTRY_POINT))
+        .ok()
+        .and_then(|entry_point| table_length(&entry_point))
+    {
+        table.truncate(length);
+    }
+    Ok(structures(&table)
+        .iter()
+        .filter(|(kind, _)| *kind == TYPE_MEMORY_DEVICE)
+        .filter_map(|(_, structure)| memory_device(structure))
+        .collect())
+}
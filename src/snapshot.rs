@@ -0,0 +1,55 @@
+use crate::memory::MemoryInfo;
+use crate::packages::{PackageCount, PACKAGE_MANAGERS};
+use crate::platform::Platform;
+use crate::probe::Probe;
+use crate::thermal::ThermalZone;
+use crate::uptime::Uptime;
+use std::collections::BTreeMap;
+
+/// Everything nixinfo can gather about a system, in one struct.
+///
+/// Info that could not be obtained is left as `None`, package managers that aren't installed are
+/// left out of `packages`, as are those counted by running a command when the probe isn't rooted
+/// at `/`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemSnapshot {
+    pub platform: Platform,
+    pub cpu: Option<String>,
+    pub device: Option<String>,
+    pub distro: Option<String>,
+    pub environment: Option<String>,
+    pub gpu: Option<Vec<String>>,
+    pub hostname: Option<String>,
+    pub kernel: Option<String>,
+    pub memory: Option<MemoryInfo>,
+    pub packages: BTreeMap<String, PackageCount>,
+    pub terminal: Option<String>,
+    pub uptime: Option<Uptime>,
+    pub temps: Option<Vec<ThermalZone>>,
+}
+
+pub fn snapshot(probe: &Probe) -> SystemSnapshot {
+    let packages = PACKAGE_MANAGERS
+        .iter()
+        .filter_map(|manager| {
+            let count = probe.packages(manager).ok()?;
+            Some((manager.to_string(), count))
+        })
+        .collect();
+    SystemSnapshot {
+        platform: probe.platform(),
+        cpu: probe.cpu().ok(),
+        device: probe.device().ok(),
+        distro: probe.distro().ok(),
+        environment: probe.environment().ok(),
+        gpu: probe.gpu().ok(),
+        hostname: probe.hostname().ok(),
+        kernel: probe.kernel().ok(),
+        memory: probe.memory().ok(),
+        packages,
+        terminal: probe.terminal().ok(),
+        uptime: probe.uptime().ok(),
+        temps: probe.temp().ok(),
+    }
+}
@@ -1,42 +1,45 @@
-use crate::shared_functions::line;
-use std::fs::File;
-use std::io::Error;
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::read;
 
-pub fn ppid(file: File) -> String {
-    let ppid = line(file, 6);
-    ppid.split(':').collect::<Vec<&str>>()[1].to_string()
+/// Finds the value of `key` in a `/proc/<pid>/status` file.
+fn status(probe: &Probe, pid: &str, key: &str) -> Result<String> {
+    let path = probe.path(format!("/proc/{}/status", pid.trim()));
+    let contents = read(&path)?;
+    contents
+        .lines()
+        .find_map(|line| line.strip_prefix(key))
+        .map(|value| value.trim_start_matches(':').trim().to_string())
+        .ok_or_else(|| Error::parse(path.display(), None, format!("no {} line", key)))
 }
 
-pub fn name(ppid: String) -> String {
-    let path = format!("/proc/{}/status", ppid.trim());
-    let file = File::open(path).unwrap();
-    let line = line(file, 0);
-    line.split(':').collect::<Vec<&str>>()[1].to_string()
+pub fn ppid(probe: &Probe, pid: &str) -> Result<String> {
+    status(probe, pid, "PPid")
 }
 
-pub fn info(process_name: String, process_id: String) -> Result<String, Error> {
-    if process_name.ends_with("sh")
+pub fn name(probe: &Probe, pid: &str) -> Result<String> {
+    status(probe, pid, "Name")
+}
+
+fn is_wrapper(process_name: &str) -> bool {
+    process_name.ends_with("sh")
         || process_name == "ion"
         || process_name == "screen"
         || process_name == "tmux"
         || process_name.starts_with("tmux")
-    {
-        let path = format!("/proc/{}/status", process_id);
-        let new_ppid = ppid(File::open(path)?).trim().replace('\n', "");
-        let new_name = name(new_ppid.clone()).trim().replace('\n', "");
-        if new_name.ends_with("sh")
-            || new_name == "ion"
-            || new_name == "screen"
-            || new_name == "tmux"
-            || new_name.starts_with("tmux")
-        {
-            let path = format!("/proc/{}/status", new_ppid);
-            let new_ppid = ppid(File::open(path)?).trim().replace('\n', "");
-            Ok(name(new_ppid).trim().replace('\n', ""))
+}
+
+pub fn info(probe: &Probe, process_name: String, process_id: String) -> Result<String> {
+    if is_wrapper(&process_name) {
+        let new_ppid = ppid(probe, &process_id)?;
+        let new_name = name(probe, &new_ppid)?;
+        if is_wrapper(&new_name) {
+            let new_ppid = ppid(probe, &new_ppid)?;
+            name(probe, &new_ppid)
         } else {
-            Ok(new_name.trim().replace('\n', ""))
+            Ok(new_name)
         }
     } else {
-        Ok(process_name.trim().replace('\n', ""))
+        Ok(process_name)
     }
 }
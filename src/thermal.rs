@@ -0,0 +1,184 @@
+use crate::shared_functions::{glob, read};
+use std::fmt;
+use std::path::Path;
+
+/// A unit to show temperatures in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    /// Converts a temperature in Celsius to this unit.
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+        }
+    }
+
+    /// "°C", "°F" or "K".
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+
+    /// Formats a temperature in Celsius in this unit with one decimal, e.g. "113.0°F".
+    pub fn format(self, celsius: f64) -> String {
+        format!("{:.1}{}", self.convert(celsius), self.symbol())
+    }
+}
+
+/// What happens when a zone reaches a trip point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TripPointKind {
+    /// The CPU gets throttled.
+    Passive,
+    /// Fans and other active cooling turn on.
+    Active,
+    /// Userspace is notified, e.g. to hibernate.
+    Hot,
+    /// The system shuts down.
+    Critical,
+}
+
+/// A `trip_point_N_temp` and `trip_point_N_type` pair of a thermal zone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TripPoint {
+    pub kind: TripPointKind,
+    pub celsius: f64,
+}
+
+/// A cooling device bound to a thermal zone, such as a fan or CPU throttling.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoolingDevice {
+    /// The device's type, e.g. "Processor", "Fan" or "intel_powerclamp".
+    pub name: String,
+    /// How hard it currently cools, from 0 up to `max_state`.
+    pub cur_state: u64,
+    pub max_state: u64,
+}
+
+/// A thermal zone from `/sys/class/thermal`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThermalZone {
+    /// The zone's type, e.g. `x86_pkg_temp` or `acpitz`.
+    pub name: String,
+    pub celsius: f64,
+    pub trip_points: Vec<TripPoint>,
+    /// The governor in charge of the zone, e.g. "step_wise".
+    pub policy: Option<String>,
+    /// "enabled" or "disabled".
+    pub mode: Option<String>,
+    pub cooling_devices: Vec<CoolingDevice>,
+}
+
+impl ThermalZone {
+    /// The temperature in Fahrenheit.
+    pub fn fahrenheit(&self) -> f64 {
+        TemperatureUnit::Fahrenheit.convert(self.celsius)
+    }
+
+    /// The temperature in Kelvin.
+    pub fn kelvin(&self) -> f64 {
+        TemperatureUnit::Kelvin.convert(self.celsius)
+    }
+
+    /// Formats the zone with its temperature in `unit`, e.g. "acpitz: 113.0°F".
+    pub fn format(&self, unit: TemperatureUnit) -> String {
+        format!("{}: {}", self.name, unit.format(self.celsius))
+    }
+}
+
+impl fmt::Display for ThermalZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.celsius)
+    }
+}
+
+fn trimmed(path: &Path) -> Option<String> {
+    read(path).ok().map(|value| value.trim().to_string())
+}
+
+fn millidegrees(path: &Path) -> Option<f64> {
+    Some(trimmed(path)?.parse::<f64>().ok()? / 1000.0)
+}
+
+fn trip_points(zone: &Path) -> Vec<TripPoint> {
+    let mut numbers: Vec<u32> = glob(zone.join("trip_point_*_temp"))
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|path| {
+            path.file_name()?
+                .to_str()?
+                .strip_prefix("trip_point_")?
+                .strip_suffix("_temp")?
+                .parse()
+                .ok()
+        })
+        .collect();
+    numbers.sort_unstable();
+    numbers
+        .into_iter()
+        .filter_map(|number| {
+            let kind = match trimmed(&zone.join(format!("trip_point_{}_type", number)))?.as_str() {
+                "passive" => TripPointKind::Passive,
+                "active" => TripPointKind::Active,
+                "hot" => TripPointKind::Hot,
+                "critical" => TripPointKind::Critical,
+                _ => return None,
+            };
+            let celsius = millidegrees(&zone.join(format!("trip_point_{}_temp", number)))?;
+            Some(TripPoint { kind, celsius })
+        })
+        .collect()
+}
+
+/// The cooling devices bound to a zone, which are linked from it as `cdevN`.
+fn cooling_devices(zone: &Path) -> Vec<CoolingDevice> {
+    let mut paths: Vec<_> = glob(zone.join("cdev[0-9]*"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .collect();
+    paths.sort_by_key(|path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("cdev"))
+            .and_then(|number| number.parse::<u32>().ok())
+    });
+    paths
+        .iter()
+        .filter_map(|path| {
+            Some(CoolingDevice {
+                name: trimmed(&path.join("type"))?,
+                cur_state: trimmed(&path.join("cur_state"))?.parse().ok()?,
+                max_state: trimmed(&path.join("max_state"))?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Reads a `thermal_zoneN` directory, or `None` if its type or temperature can't be read. Some
+/// laptops have zones whose `temp` always fails with EIO or ENODATA.
+pub fn zone(path: &Path) -> Option<ThermalZone> {
+    Some(ThermalZone {
+        name: trimmed(&path.join("type"))?,
+        celsius: millidegrees(&path.join("temp"))?,
+        trip_points: trip_points(path),
+        policy: trimmed(&path.join("policy")),
+        mode: trimmed(&path.join("mode")),
+        cooling_devices: cooling_devices(path),
+    })
+}
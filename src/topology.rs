@@ -0,0 +1,217 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::{cpu_list, glob, read};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// The kind of a core on hybrid CPUs, such as Intel's Alder Lake and later.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoreKind {
+    /// A P-core, listed in `/sys/devices/cpu_core/cpus`.
+    Performance,
+    /// An E-core, listed in `/sys/devices/cpu_atom/cpus`.
+    Efficiency,
+}
+
+/// Where one online logical CPU sits in the topology.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogicalCpu {
+    /// The N in `cpuN`.
+    pub cpu: u32,
+    /// `physical_package_id`, i.e. the socket.
+    pub package: Option<u32>,
+    /// `core_id`, only unique within a package.
+    pub core: Option<u32>,
+    /// Only set on hybrid CPUs.
+    pub kind: Option<CoreKind>,
+    pub node: Option<u32>,
+}
+
+/// One CPU cache, along with the CPUs sharing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cache {
+    /// 1 for L1, 2 for L2 and so on.
+    pub level: u32,
+    /// "Data", "Instruction" or "Unified".
+    pub kind: String,
+    /// The size in bytes.
+    pub size: u64,
+    pub shared_cpus: Vec<u32>,
+}
+
+/// A NUMA node and the CPUs that belong to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumaNode {
+    pub id: u32,
+    pub cpus: Vec<u32>,
+}
+
+/// How the CPUs of the system are laid out, from `/sys/devices/system/cpu/` and
+/// `/sys/devices/system/node/`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuTopology {
+    /// Physical packages (sockets).
+    pub packages: u32,
+    pub cores_per_package: u32,
+    /// The most threads any core has, 2 with SMT enabled on most x86 CPUs.
+    pub threads_per_core: u32,
+    pub online: Vec<u32>,
+    pub offline: Vec<u32>,
+    pub cpus: Vec<LogicalCpu>,
+    /// Every cache once, ordered by level.
+    pub caches: Vec<Cache>,
+    pub numa_nodes: Vec<NumaNode>,
+}
+
+impl CpuTopology {
+    /// The combined size in bytes of every cache at `level`, e.g. all L2 caches of all cores.
+    pub fn cache_size(&self, level: u32) -> u64 {
+        self.caches
+            .iter()
+            .filter(|cache| cache.level == level)
+            .map(|cache| cache.size)
+            .sum()
+    }
+}
+
+fn number(path: &Path) -> Option<u32> {
+    read(path).ok()?.trim().parse().ok()
+}
+
+/// Reads a CPU list file, giving `None` if it doesn't exist.
+fn read_cpu_list(path: &Path) -> Result<Option<Vec<u32>>> {
+    match read(path) {
+        Ok(list) => cpu_list(&list, &path.display().to_string()).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Parses a cache size such as "32K" or "8M".
+fn cache_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let (amount, multiplier) = match size.chars().last()? {
+        'K' => (&size[..size.len() - 1], 1024),
+        'M' => (&size[..size.len() - 1], 1024 * 1024),
+        'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+    Some(amount.parse::<u64>().ok()? * multiplier)
+}
+
+/// The N in a path ending in e.g. `cpuN` or `nodeN`.
+fn index(path: &Path, prefix: &str) -> Option<u32> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .parse()
+        .ok()
+}
+
+pub fn topology(probe: &Probe) -> Result<CpuTopology> {
+    let cpu_dir = probe.path("/sys/devices/system/cpu");
+    let mut all: Vec<u32> = glob(cpu_dir.join("cpu[0-9]*"))?
+        .iter()
+        .filter_map(|path| index(path, "cpu"))
+        .collect();
+    all.sort_unstable();
+    if all.is_empty() {
+        return Err(Error::NotFound(
+            "CPUs in /sys/devices/system/cpu".to_string(),
+        ));
+    }
+    let online = read_cpu_list(&cpu_dir.join("online"))?.unwrap_or_else(|| all.clone());
+    let offline = read_cpu_list(&cpu_dir.join("offline"))?.unwrap_or_else(|| {
+        all.iter()
+            .filter(|cpu| !online.contains(cpu))
+            .copied()
+            .collect()
+    });
+
+    let mut kinds = BTreeMap::new();
+    for (path, kind) in [
+        ("/sys/devices/cpu_core/cpus", CoreKind::Performance),
+        ("/sys/devices/cpu_atom/cpus", CoreKind::Efficiency),
+    ] {
+        for cpu in read_cpu_list(&probe.path(path))?.unwrap_or_default() {
+            kinds.insert(cpu, kind);
+        }
+    }
+
+    let mut numa_nodes = Vec::new();
+    for path in glob(probe.path("/sys/devices/system/node/node[0-9]*"))? {
+        if let Some(id) = index(&path, "node") {
+            let cpus = read_cpu_list(&path.join("cpulist"))?.unwrap_or_default();
+            numa_nodes.push(NumaNode { id, cpus });
+        }
+    }
+    numa_nodes.sort_by_key(|node| node.id);
+
+    let mut cpus = Vec::new();
+    let mut caches: BTreeSet<(u32, String, Vec<u32>, u64)> = BTreeSet::new();
+    for &cpu in &online {
+        let dir = cpu_dir.join(format!("cpu{}", cpu));
+        cpus.push(LogicalCpu {
+            cpu,
+            package: number(&dir.join("topology/physical_package_id")),
+            core: number(&dir.join("topology/core_id")),
+            kind: kinds.get(&cpu).copied(),
+            node: numa_nodes
+                .iter()
+                .find(|node| node.cpus.contains(&cpu))
+                .map(|node| node.id),
+        });
+        for index in glob(dir.join("cache/index[0-9]*"))? {
+            let level = number(&index.join("level"));
+            let size = read(index.join("size"))
+                .ok()
+                .and_then(|size| cache_size(&size));
+            let (level, size) = match (level, size) {
+                (Some(level), Some(size)) => (level, size),
+                _ => continue,
+            };
+            let kind = read(index.join("type"))
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            let shared_cpus =
+                read_cpu_list(&index.join("shared_cpu_list"))?.unwrap_or_else(|| vec![cpu]);
+            caches.insert((level, kind, shared_cpus, size));
+        }
+    }
+
+    // Threads are grouped into cores by package and core id, since core ids repeat per package.
+    let mut cores: BTreeMap<(Option<u32>, Option<u32>), u32> = BTreeMap::new();
+    for cpu in &cpus {
+        *cores.entry((cpu.package, cpu.core)).or_default() += 1;
+    }
+    let packages = cpus
+        .iter()
+        .map(|cpu| cpu.package)
+        .collect::<BTreeSet<_>>()
+        .len()
+        .max(1) as u32;
+
+    Ok(CpuTopology {
+        packages,
+        cores_per_package: (cores.len() as u32 / packages).max(1),
+        threads_per_core: cores.values().copied().max().unwrap_or(1),
+        online,
+        offline,
+        cpus,
+        caches: caches
+            .into_iter()
+            .map(|(level, kind, shared_cpus, size)| Cache {
+                level,
+                kind,
+                size,
+                shared_cpus,
+            })
+            .collect(),
+        numa_nodes,
+    })
+}
@@ -1,3 +1,19 @@
+use std::fmt;
+use std::time::Duration;
+
+/// How long the system has been running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uptime(pub Duration);
+
+impl fmt::Display for Uptime {
+    /// Formats as e.g. "3d 4h 5m", leaving out units that are still zero.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (days, hours, minutes) = duration(self.0.as_secs() as i64);
+        write!(f, "{}", format!("{} {} {}", days, hours, minutes).trim())
+    }
+}
+
 pub fn duration(uptime: i64) -> (String, String, String) {
     let days = if uptime > 86400 {
         let days_pre = uptime / 60 / 60 / 24;
@@ -0,0 +1,182 @@
+use crate::dmi::dmi;
+use crate::probe::Probe;
+use crate::shared_functions::read;
+use std::fmt;
+
+/// The hypervisor a virtual machine runs under, named like `systemd-detect-virt --vm` does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VirtKind {
+    Kvm,
+    /// QEMU without KVM, i.e. emulated.
+    Qemu,
+    Vmware,
+    VirtualBox,
+    HyperV,
+    Xen,
+    Parallels,
+    Bhyve,
+    Bochs,
+    /// An Amazon EC2 instance using the Nitro hypervisor.
+    Amazon,
+    /// A Google Compute Engine instance.
+    Google,
+    /// Apple's Virtualization framework on macOS.
+    Apple,
+    /// The CPU reports a hypervisor that nixinfo doesn't recognise.
+    Other,
+}
+
+impl fmt::Display for VirtKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            VirtKind::Kvm => "KVM",
+            VirtKind::Qemu => "QEMU",
+            VirtKind::Vmware => "VMware",
+            VirtKind::VirtualBox => "VirtualBox",
+            VirtKind::HyperV => "Hyper-V",
+            VirtKind::Xen => "Xen",
+            VirtKind::Parallels => "Parallels",
+            VirtKind::Bhyve => "bhyve",
+            VirtKind::Bochs => "Bochs",
+            VirtKind::Amazon => "Amazon EC2",
+            VirtKind::Google => "Google Compute Engine",
+            VirtKind::Apple => "Apple Virtualization",
+            VirtKind::Other => "Other",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A virtual machine and what runs it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Virt {
+    pub kind: VirtKind,
+    /// The vendor the firmware reports, e.g. "QEMU", "VMware, Inc." or "innotek GmbH".
+    pub vendor: Option<String>,
+}
+
+/// Prefixes of DMI strings that give away a hypervisor, the same ones systemd looks for.
+const DMI_VENDORS: &[(&str, VirtKind)] = &[
+    ("KVM", VirtKind::Kvm),
+    ("OpenStack", VirtKind::Kvm),
+    ("KubeVirt", VirtKind::Kvm),
+    ("Amazon EC2", VirtKind::Amazon),
+    ("QEMU", VirtKind::Qemu),
+    ("VMware", VirtKind::Vmware),
+    ("VMW", VirtKind::Vmware),
+    ("innotek GmbH", VirtKind::VirtualBox),
+    ("VirtualBox", VirtKind::VirtualBox),
+    ("Oracle Corporation", VirtKind::VirtualBox),
+    ("Xen", VirtKind::Xen),
+    ("Bochs", VirtKind::Bochs),
+    ("Parallels", VirtKind::Parallels),
+    ("BHYVE", VirtKind::Bhyve),
+    ("Hyper-V", VirtKind::HyperV),
+    ("Apple Virtualization", VirtKind::Apple),
+    ("Google Compute Engine", VirtKind::Google),
+];
+
+/// Matches the DMI product, vendor and BIOS strings against `DMI_VENDORS`.
+fn from_dmi(probe: &Probe) -> Option<Virt> {
+    let dmi = dmi(probe).ok()?;
+    // Hyper-V only identifies itself by product name.
+    if dmi.sys_vendor.as_deref() == Some("Microsoft Corporation")
+        && dmi.product_name.as_deref() == Some("Virtual Machine")
+    {
+        return Some(Virt {
+            kind: VirtKind::HyperV,
+            vendor: dmi.sys_vendor,
+        });
+    }
+    let fields = [
+        &dmi.product_name,
+        &dmi.sys_vendor,
+        &dmi.board_vendor,
+        &dmi.bios_vendor,
+        &dmi.product_version,
+    ];
+    let kind = fields.iter().find_map(|field| {
+        let field = field.as_deref()?;
+        DMI_VENDORS
+            .iter()
+            .find(|(prefix, _)| field.starts_with(prefix))
+            .map(|(_, kind)| *kind)
+    })?;
+    // Bare metal EC2 instances have the same vendor as virtual ones.
+    if kind == VirtKind::Amazon
+        && dmi
+            .product_name
+            .as_deref()
+            .is_some_and(|product| product.ends_with(".metal"))
+    {
+        return None;
+    }
+    Some(Virt {
+        kind,
+        vendor: dmi.sys_vendor,
+    })
+}
+
+/// Looks at the hypervisor node ARM and POWER guests get in their devicetree.
+fn from_devicetree(probe: &Probe) -> Option<VirtKind> {
+    let compatible =
+        read(probe.path("/sys/firmware/devicetree/base/hypervisor/compatible")).ok()?;
+    if compatible.contains("linux,kvm") {
+        Some(VirtKind::Kvm)
+    } else if compatible.contains("xen") {
+        Some(VirtKind::Xen)
+    } else if compatible.contains("vmware") {
+        Some(VirtKind::Vmware)
+    } else {
+        Some(VirtKind::Other)
+    }
+}
+
+/// The paravirtualised clocks the kernel found, which tell KVM apart from plain QEMU.
+fn from_clocksource(probe: &Probe) -> Option<VirtKind> {
+    let clocksources =
+        read(probe.path("/sys/devices/system/clocksource/clocksource0/available_clocksource"))
+            .ok()?;
+    clocksources
+        .split_whitespace()
+        .find_map(|clocksource| match clocksource {
+            "kvm-clock" => Some(VirtKind::Kvm),
+            "xen" => Some(VirtKind::Xen),
+            clocksource if clocksource.starts_with("hyperv_clocksource") => Some(VirtKind::HyperV),
+            _ => None,
+        })
+}
+
+pub fn virtualization(probe: &Probe) -> Option<Virt> {
+    // A Xen dom0 runs on the hypervisor but manages it, so it isn't a guest.
+    let xen_capabilities = read(probe.path("/proc/xen/capabilities")).unwrap_or_default();
+    if xen_capabilities.contains("control_d") {
+        return None;
+    }
+    let clocksource = from_clocksource(probe);
+    if let Some(mut virt) = from_dmi(probe) {
+        // QEMU is the vendor under KVM as well.
+        if virt.kind == VirtKind::Qemu && clocksource == Some(VirtKind::Kvm) {
+            virt.kind = VirtKind::Kvm;
+        }
+        return Some(virt);
+    }
+    let kind = if probe.path("/proc/xen").exists()
+        || read(probe.path("/sys/hypervisor/type")).is_ok_and(|kind| kind.trim() == "xen")
+    {
+        Some(VirtKind::Xen)
+    } else {
+        from_devicetree(probe).or(clocksource)
+    };
+    let kind = kind.or_else(|| {
+        let cpu = probe.cpu_info().ok()?;
+        if cpu.flags.iter().any(|flag| flag == "hypervisor") {
+            Some(VirtKind::Other)
+        } else {
+            None
+        }
+    })?;
+    Some(Virt { kind, vendor: None })
+}
@@ -0,0 +1,127 @@
+use crate::error::{Error, Result};
+use crate::probe::Probe;
+use crate::shared_functions::{glob, read};
+
+/// Whether the CPU is affected by a vulnerability, and if so whether the kernel mitigates it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VulnerabilityStatus {
+    Vulnerable,
+    Mitigated,
+    NotAffected,
+    /// The kernel can't tell, or reported something nixinfo doesn't know.
+    Unknown,
+}
+
+/// One file of `/sys/devices/system/cpu/vulnerabilities/`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vulnerability {
+    /// The file name, e.g. "spectre_v2".
+    pub name: String,
+    pub status: VulnerabilityStatus,
+    /// What the kernel said after the status, e.g. "Enhanced / Automatic IBRS; IBPB: conditional"
+    /// for a mitigation.
+    pub detail: Option<String>,
+}
+
+/// The state of simultaneous multithreading, from `/sys/devices/system/cpu/smt/control`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SmtControl {
+    On,
+    Off,
+    /// Disabled with `nosmt=force`, it can't be turned back on until reboot.
+    ForceOff,
+    /// The CPU has no SMT.
+    NotSupported,
+    /// The architecture doesn't support SMT control.
+    NotImplemented,
+    /// The number of threads per core that may be enabled, as used on POWER.
+    Threads(u32),
+}
+
+/// SMT control along with whether sibling threads are actually running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Smt {
+    pub control: SmtControl,
+    /// `/sys/devices/system/cpu/smt/active`
+    pub active: Option<bool>,
+}
+
+fn parse(name: String, contents: &str) -> Vulnerability {
+    let contents = contents.trim();
+    // itlb_multihit reports the state for KVM guests, e.g. "KVM: Mitigation: VMX disabled", which
+    // stays in the detail.
+    let (kvm, status) = match contents.strip_prefix("KVM: ") {
+        Some(rest) => ("KVM: ", rest),
+        None => ("", contents),
+    };
+    let detail = |rest: &str| {
+        let rest = rest.trim_start_matches(':').trim();
+        if rest.is_empty() && kvm.is_empty() {
+            None
+        } else {
+            Some(format!("{}{}", kvm, rest).trim().to_string())
+        }
+    };
+    let (status, detail) = if status == "Not affected" {
+        (VulnerabilityStatus::NotAffected, detail(""))
+    } else if let Some(rest) = status.strip_prefix("Mitigation") {
+        (VulnerabilityStatus::Mitigated, detail(rest))
+    } else if let Some(rest) = status
+        .strip_prefix("Vulnerable")
+        // What older kernels write.
+        .or_else(|| status.strip_prefix("Processor vulnerable"))
+    {
+        (VulnerabilityStatus::Vulnerable, detail(rest))
+    } else {
+        (VulnerabilityStatus::Unknown, detail(status))
+    };
+    Vulnerability {
+        name,
+        status,
+        detail,
+    }
+}
+
+pub fn vulnerabilities(probe: &Probe) -> Result<Vec<Vulnerability>> {
+    let paths = glob(probe.path("/sys/devices/system/cpu/vulnerabilities/*"))?;
+    if paths.is_empty() {
+        return Err(Error::NotFound(
+            "/sys/devices/system/cpu/vulnerabilities".to_string(),
+        ));
+    }
+    let mut vulnerabilities = Vec::new();
+    for path in paths {
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        vulnerabilities.push(parse(name, &read(&path)?));
+    }
+    Ok(vulnerabilities)
+}
+
+pub fn smt(probe: &Probe) -> Result<Smt> {
+    let path = probe.path("/sys/devices/system/cpu/smt/control");
+    let control = match read(&path)?.trim() {
+        "on" => SmtControl::On,
+        "off" => SmtControl::Off,
+        "forceoff" => SmtControl::ForceOff,
+        "notsupported" => SmtControl::NotSupported,
+        "notimplemented" => SmtControl::NotImplemented,
+        other => SmtControl::Threads(other.parse().map_err(|_| {
+            Error::parse(
+                path.display(),
+                Some(1),
+                format!("unknown SMT control `{}`", other),
+            )
+        })?),
+    };
+    let active = read(probe.path("/sys/devices/system/cpu/smt/active"))
+        .ok()
+        .map(|active| active.trim() == "1");
+    Ok(Smt { control, active })
+}
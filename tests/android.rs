@@ -0,0 +1,193 @@
+mod common;
+
+use common::scratch;
+use nixinfo::{Error, Probe};
+use std::fs;
+use std::path::Path;
+
+/// A property area as bionic lays it out: a 128 byte header, then a trie of `prop_bt` nodes and
+/// `prop_info` values that refer to each other by offsets from the end of the header.
+struct Area {
+    data: Vec<u8>,
+}
+
+impl Area {
+    fn new() -> Area {
+        let mut area = Area { data: Vec::new() };
+        area.node("");
+        area
+    }
+
+    fn align(&mut self) {
+        while self.data.len() % 4 != 0 {
+            self.data.push(0);
+        }
+    }
+
+    fn push_u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn set_u32(&mut self, offset: usize, value: usize) {
+        self.data[offset..offset + 4].copy_from_slice(&(value as u32).to_le_bytes());
+    }
+
+    /// Adds a `prop_bt`: namelen, prop, left, right and children, then the name.
+    fn node(&mut self, name: &str) -> usize {
+        self.align();
+        let offset = self.data.len();
+        self.push_u32(name.len() as u32);
+        for _ in 0..4 {
+            self.push_u32(0);
+        }
+        self.data.extend_from_slice(name.as_bytes());
+        self.data.push(0);
+        offset
+    }
+
+    fn set_prop(&mut self, node: usize, info: usize) {
+        self.set_u32(node + 4, info);
+    }
+
+    fn set_left(&mut self, node: usize, left: usize) {
+        self.set_u32(node + 8, left);
+    }
+
+    fn set_right(&mut self, node: usize, right: usize) {
+        self.set_u32(node + 12, right);
+    }
+
+    fn set_children(&mut self, node: usize, children: usize) {
+        self.set_u32(node + 16, children);
+    }
+
+    /// Adds a `prop_info`: the serial, a 92 byte value, then the name.
+    fn info(&mut self, name: &str, value: &str) -> usize {
+        self.align();
+        let offset = self.data.len();
+        self.push_u32((value.len() as u32) << 24);
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.resize(92, 0);
+        self.data.extend_from_slice(&bytes);
+        self.data.extend_from_slice(name.as_bytes());
+        self.data.push(0);
+        offset
+    }
+
+    /// Adds a `prop_info` for a value too long for the 92 bytes, which is stored after the name
+    /// with its offset from the `prop_info` at byte 56 of the value.
+    fn long_info(&mut self, name: &str, value: &str) -> usize {
+        let offset = self.info(name, "Must use __system_property_read_callback()");
+        self.set_u32(offset, 1 << 16);
+        let long = self.data.len() - offset;
+        self.set_u32(offset + 4 + 56, long);
+        self.data.extend_from_slice(value.as_bytes());
+        self.data.push(0);
+        offset
+    }
+
+    fn write(&self, root: &Path) {
+        let dir = root.join("dev/__properties__");
+        fs::create_dir_all(&dir).unwrap();
+        let mut area = vec![0; 128];
+        area[8..12].copy_from_slice(&0x504f_5250_u32.to_le_bytes());
+        area[12..16].copy_from_slice(&0xfc6e_d0ab_u32.to_le_bytes());
+        area.extend_from_slice(&self.data);
+        fs::write(dir.join("u:object_r:default_prop:s0"), area).unwrap();
+    }
+}
+
+const FINGERPRINT: &str = "google/husky/husky:14/UQ1A.240105.004/11206848:user/release-keys \
+                           with enough text to go past the 92 bytes of a short value";
+
+/// Holds `ro.product.model`, and `ro.build.fingerprint` as a long value, in a property area,
+/// and `ro.build.version.sdk` in build.prop.
+fn android() -> Probe {
+    let root = scratch("android");
+    let mut area = Area::new();
+    let ro = area.node("ro");
+    area.set_children(0, ro);
+    // Siblings are a binary tree ordered by length, so "build" is left of "product".
+    let product = area.node("product");
+    area.set_children(ro, product);
+    let build = area.node("build");
+    area.set_left(product, build);
+    let model = area.node("model");
+    area.set_children(product, model);
+    let info = area.info("ro.product.model", "Pixel 8 Pro");
+    area.set_prop(model, info);
+    let fingerprint = area.node("fingerprint");
+    area.set_children(build, fingerprint);
+    let info = area.long_info("ro.build.fingerprint", FINGERPRINT);
+    area.set_prop(fingerprint, info);
+    area.write(&root);
+
+    fs::create_dir_all(root.join("system")).unwrap();
+    fs::write(
+        root.join("system/build.prop"),
+        "# build.prop\nro.product.model=Overridden\nro.build.version.sdk=34\n",
+    )
+    .unwrap();
+    Probe::with_root(root)
+}
+
+#[test]
+fn property_area() {
+    let probe = android();
+    assert_eq!(
+        probe.android_property("ro.product.model").unwrap(),
+        "Pixel 8 Pro"
+    );
+    assert_eq!(
+        probe.android_property("ro.build.fingerprint").unwrap(),
+        FINGERPRINT
+    );
+    match probe.android_property("ro.product.brand") {
+        Err(Error::NotFound(_)) => {}
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn prop_file() {
+    let info = android().android_info().unwrap();
+    assert_eq!(info.sdk, Some(34));
+    // The property area wins over build.prop.
+    assert_eq!(info.model.as_deref(), Some("Pixel 8 Pro"));
+}
+
+#[test]
+fn cyclic_area() {
+    let root = scratch("android-cyclic");
+    let mut area = Area::new();
+    let rn = area.node("rn");
+    area.set_children(0, rn);
+    let b = area.node("b");
+    area.set_children(rn, b);
+    // Looking up "c" goes right of "b", which leads back to "b".
+    area.set_right(b, b);
+    area.write(&root);
+    match Probe::with_root(root).android_property("rn.c") {
+        Err(Error::NotFound(_)) => {}
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn missing_property_with_readable_sources() {
+    // `ro.product.name` is usually derived at boot and missing from build.prop. getprop would be
+    // asked next, but only for a probe of the running system.
+    match android().android_property("ro.product.name") {
+        Err(Error::NotFound(_)) => {}
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn getprop_is_not_run_for_other_roots() {
+    let root = scratch("android-unreadable");
+    match Probe::with_root(root).android_property("ro.product.model") {
+        Err(Error::Unsupported(_)) => {}
+        other => panic!("expected Unsupported, got {:?}", other),
+    }
+}
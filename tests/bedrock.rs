@@ -0,0 +1,30 @@
+mod common;
+
+use common::fixture;
+
+#[test]
+fn strata() {
+    let strata = fixture("bedrock").bedrock_strata().unwrap();
+    let names: Vec<&str> = strata.iter().map(|stratum| stratum.name.as_str()).collect();
+    // The `init` and `local` aliases aren't strata of their own.
+    assert_eq!(names, ["arch", "bedrock", "void"]);
+
+    assert_eq!(strata[0].distro.as_deref(), Some("Arch Linux"));
+    assert_eq!(strata[2].distro.as_deref(), Some("Void"));
+    assert_eq!(strata[1].distro, None);
+    assert_eq!(
+        strata
+            .iter()
+            .map(|stratum| stratum.enabled)
+            .collect::<Vec<_>>(),
+        [true, true, false]
+    );
+    // Found from the `init` alias, which unlike `/proc/1/root` needs no privileges.
+    assert_eq!(
+        strata
+            .iter()
+            .map(|stratum| stratum.provides_init)
+            .collect::<Vec<_>>(),
+        [true, false, false]
+    );
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::fixture;
+use nixinfo::CgroupVersion;
+
+#[test]
+fn v2() {
+    let cgroup = fixture("cgroup-v2").cgroup().unwrap();
+    assert_eq!(cgroup.version, CgroupVersion::V2);
+    assert_eq!(cgroup.path, "/system.slice/docker-abc.scope");
+    // The parent's limit is lower than the container's own.
+    assert_eq!(cgroup.memory_max, Some(2 * 1024 * 1024 * 1024));
+    assert_eq!(cgroup.memory_current, Some(512 * 1024 * 1024));
+    assert_eq!(cgroup.cpu_max, Some(1.5));
+    assert_eq!(cgroup.cpuset, Some(vec![0, 1, 4]));
+}
+
+#[test]
+fn v1() {
+    let cgroup = fixture("cgroup-v1").cgroup().unwrap();
+    assert_eq!(cgroup.version, CgroupVersion::V1);
+    assert_eq!(cgroup.path, "/docker/abc");
+    assert_eq!(cgroup.memory_max, Some(1024 * 1024 * 1024));
+    assert_eq!(cgroup.memory_current, Some(256 * 1024 * 1024));
+    assert_eq!(cgroup.cpu_max, Some(0.5));
+    assert_eq!(cgroup.cpuset, Some(vec![2, 3]));
+}
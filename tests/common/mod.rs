@@ -0,0 +1,23 @@
+// Each test file is its own crate, and not all of them use every helper.
+#![allow(dead_code)]
+
+use nixinfo::Probe;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A probe rooted at `tests/fixtures/<name>`.
+pub fn fixture(name: &str) -> Probe {
+    Probe::with_root(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name),
+    )
+}
+
+/// An empty directory for a test to build its own fixture tree in.
+pub fn scratch(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
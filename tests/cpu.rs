@@ -0,0 +1,57 @@
+mod common;
+
+use common::fixture;
+
+#[test]
+fn x86() {
+    let probe = fixture("cpu-x86");
+    let cpu = probe.cpu_info().unwrap();
+    assert_eq!(cpu.vendor.as_deref(), Some("GenuineIntel"));
+    assert_eq!(
+        cpu.model_name.as_deref(),
+        Some("Intel(R) Core(TM) i7-8700 CPU @ 3.20GHz")
+    );
+    assert_eq!(
+        (cpu.family, cpu.model, cpu.stepping),
+        (Some(6), Some(158), Some(10))
+    );
+    assert_eq!(cpu.microcode.as_deref(), Some("0xf4"));
+    assert_eq!((cpu.sockets, cpu.cores, cpu.threads), (1, 2, 4));
+    assert_eq!(cpu.cache_size, Some(12288 * 1024));
+    assert_eq!(cpu.bogomips, Some(6384.0));
+    assert!(cpu.flags.iter().any(|flag| flag == "avx2"));
+    // Without cpufreq the base clock in the name stays.
+    assert_eq!(probe.cpu().unwrap(), "Intel Core i7-8700 CPU @ 3.20GHz");
+}
+
+#[test]
+fn arm() {
+    let cpu = fixture("cpu-arm").cpu_info().unwrap();
+    assert_eq!(cpu.vendor.as_deref(), Some("ARM"));
+    assert_eq!(cpu.model_name.as_deref(), Some("ARM Cortex-A72"));
+    assert_eq!(
+        (cpu.family, cpu.model, cpu.stepping),
+        (Some(8), Some(0xd08), Some(3))
+    );
+    assert_eq!(cpu.flags, ["fp", "asimd", "evtstrm", "crc32", "cpuid"]);
+    assert_eq!(cpu.threads, 2);
+    assert_eq!(cpu.bogomips, Some(108.0));
+    assert_eq!(cpu.hardware.as_deref(), Some("BCM2835"));
+}
+
+#[test]
+fn riscv() {
+    let cpu = fixture("cpu-riscv").cpu_info().unwrap();
+    assert_eq!(cpu.isa.as_deref(), Some("rv64imafdc"));
+    assert_eq!(cpu.uarch.as_deref(), Some("sifive,u74-mc"));
+    assert_eq!(cpu.model_name.as_deref(), Some("sifive,u74-mc"));
+    assert_eq!(cpu.threads, 2);
+}
+
+#[test]
+fn power() {
+    let cpu = fixture("cpu-power").cpu_info().unwrap();
+    assert_eq!(cpu.vendor.as_deref(), Some("IBM"));
+    assert_eq!(cpu.model_name.as_deref(), Some("POWER9 (raw)"));
+    assert_eq!(cpu.threads, 2);
+}
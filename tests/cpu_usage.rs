@@ -0,0 +1,44 @@
+mod common;
+
+use common::scratch;
+use nixinfo::{CpuSampler, Probe};
+use std::fs;
+use std::path::Path;
+
+fn sample(root: &Path, name: &str) {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/stat")
+        .join(name);
+    fs::copy(fixture, root.join("proc/stat")).unwrap();
+}
+
+#[test]
+fn poll() {
+    let root = scratch("cpu-usage");
+    fs::create_dir_all(root.join("proc")).unwrap();
+    let mut sampler = CpuSampler::with_probe(Probe::with_root(&root));
+
+    sample(&root, "first");
+    // The first poll is the average since boot.
+    let usage = sampler.poll().unwrap();
+    assert_eq!(usage.total.idle, 80.0);
+    assert_eq!(usage.total.steal, 1.0);
+
+    sample(&root, "second");
+    let usage = sampler.poll().unwrap();
+    assert_eq!(usage.total.user, 40.0);
+    assert_eq!(usage.total.system, 10.0);
+    assert_eq!(usage.total.idle, 40.0);
+    assert_eq!(usage.total.iowait, 5.0);
+    assert_eq!(usage.total.softirq, 5.0);
+    assert_eq!(usage.total.busy(), 55.0);
+    assert_eq!(usage.cores.keys().copied().collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(usage.cores[&0].user, 70.0);
+    assert_eq!(usage.cores[&0].busy(), 80.0);
+    assert_eq!(usage.cores[&1].idle, 60.0);
+    assert_eq!(usage.cores[&1].busy(), 30.0);
+
+    // Nothing happened since the last poll.
+    let usage = sampler.poll().unwrap();
+    assert_eq!(usage.total.busy(), 0.0);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::fixture;
+use nixinfo::Frequency;
+
+#[test]
+fn cpus() {
+    let cpus = fixture("cpufreq").cpu_freq().unwrap();
+    // cpu2 is offline and has no cpufreq directory, and cpu10 sorts by number.
+    assert_eq!(
+        cpus.iter().map(|cpu| cpu.cpu).collect::<Vec<_>>(),
+        [0, 1, 10]
+    );
+    assert_eq!(cpus[0].current, Some(Frequency(3_192_000)));
+    assert_eq!(cpus[0].min, Some(Frequency(800_000)));
+    assert_eq!(cpus[0].max, Some(Frequency(4_600_000)));
+    assert_eq!(cpus[0].governor.as_deref(), Some("powersave"));
+    assert_eq!(cpus[0].driver.as_deref(), Some("intel_pstate"));
+    // Without cpuinfo_* files the scaling limits are used.
+    assert_eq!(cpus[2].min, Some(Frequency(800_000)));
+    assert_eq!(cpus[2].max, Some(Frequency(4_600_000)));
+}
+
+#[test]
+fn max_freq() {
+    let probe = fixture("cpufreq");
+    assert_eq!(probe.cpu_max_freq().unwrap(), Frequency(4_700_000));
+    // The boost clock replaces the base clock in the model name.
+    assert_eq!(probe.cpu().unwrap(), "Intel Core i7-8700 CPU @ 4.70 GHz");
+}
+
+#[test]
+fn frequency_display() {
+    assert_eq!(Frequency(4_700_000).to_string(), "4.70 GHz");
+    assert_eq!(Frequency(1_000_000).to_string(), "1.00 GHz");
+    assert_eq!(Frequency(800_000).to_string(), "800 MHz");
+}
+
+#[test]
+fn no_cpufreq() {
+    assert!(fixture("cpu-x86").cpu_max_freq().is_err());
+}
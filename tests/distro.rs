@@ -0,0 +1,47 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{DistroSource, Error};
+use std::path::PathBuf;
+
+#[test]
+fn lsb_release() {
+    let distro = fixture("lsb-release").distro_info().unwrap();
+    assert_eq!(distro.name, "Ubuntu");
+    assert_eq!(distro.source, DistroSource::LsbRelease);
+}
+
+#[test]
+fn release_file_with_name() {
+    let distro = fixture("redhat-release").distro_info().unwrap();
+    assert_eq!(distro.name, "CentOS");
+    assert_eq!(
+        distro.source,
+        DistroSource::ReleaseFile(PathBuf::from("/etc/redhat-release"))
+    );
+}
+
+#[test]
+fn release_file_with_version() {
+    let distro = fixture("debian-version").distro_info().unwrap();
+    assert_eq!(distro.name, "Debian");
+    assert_eq!(
+        distro.source,
+        DistroSource::ReleaseFile(PathBuf::from("/etc/debian_version"))
+    );
+}
+
+#[test]
+fn issue_drops_escapes() {
+    let distro = fixture("issue-arch").distro_info().unwrap();
+    assert_eq!(distro.name, "Arch Linux");
+    assert_eq!(distro.source, DistroSource::Issue);
+}
+
+#[test]
+fn issue_of_only_escapes_has_no_name() {
+    match fixture("issue-agetty").distro_info() {
+        Err(Error::NotFound(_)) => {}
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
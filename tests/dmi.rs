@@ -0,0 +1,52 @@
+mod common;
+
+use common::fixture;
+use nixinfo::Error;
+
+#[test]
+fn thinkpad() {
+    let probe = fixture("dmi-thinkpad");
+    let dmi = probe.dmi().unwrap();
+    assert_eq!(dmi.sys_vendor.as_deref(), Some("LENOVO"));
+    assert_eq!(dmi.bios_version.as_deref(), Some("N32ET86W (1.62 )"));
+    assert_eq!(dmi.chassis_type.as_deref(), Some("Notebook"));
+    // Lenovo puts the model in the version and a machine type in the product name.
+    assert_eq!(
+        dmi.device_name().as_deref(),
+        Some("LENOVO 20XWCTO1WW (ThinkPad X1 Carbon Gen 9)")
+    );
+    assert_eq!(
+        probe.device().unwrap(),
+        "LENOVO 20XWCTO1WW (ThinkPad X1 Carbon Gen 9)"
+    );
+}
+
+#[test]
+fn placeholders_fall_back_to_the_board() {
+    let dmi = fixture("dmi-desktop").dmi().unwrap();
+    // Placeholders are still reported as they are, only device_name() leaves them out.
+    assert_eq!(dmi.product_name.as_deref(), Some("System Product Name"));
+    assert_eq!(dmi.chassis_type.as_deref(), Some("Desktop"));
+    assert_eq!(
+        dmi.device_name().as_deref(),
+        Some("ASUSTeK COMPUTER INC. PRIME X570-PRO (Rev X.0x)")
+    );
+}
+
+#[test]
+fn vendor_in_product() {
+    let dmi = fixture("dmi-hp").dmi().unwrap();
+    // The whitespace-only version is read as unset.
+    assert_eq!(dmi.product_version, None);
+    // Chassis types past the known ones are kept as the number.
+    assert_eq!(dmi.chassis_type.as_deref(), Some("99"));
+    assert_eq!(dmi.device_name().as_deref(), Some("HP EliteBook 840 G5"));
+}
+
+#[test]
+fn no_dmi() {
+    match fixture("cpu-arm").dmi() {
+        Err(Error::NotFound(_)) => {}
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::fixture;
+use std::time::Duration;
+
+#[test]
+fn kernel() {
+    assert_eq!(fixture("system").kernel().unwrap(), "6.6.8-arch1-1");
+}
+
+#[test]
+fn hostname() {
+    assert_eq!(fixture("system").hostname().unwrap(), "nixbox");
+    // Without /etc/hostname, as in many containers, the kernel's is used.
+    assert_eq!(
+        fixture("kernel-hostname").hostname().unwrap(),
+        "container-1234"
+    );
+}
+
+#[test]
+fn uptime() {
+    let uptime = fixture("system").uptime().unwrap();
+    assert_eq!(uptime.0, Duration::from_secs_f64(273_932.47));
+    assert_eq!(uptime.to_string(), "3d 4h 5m");
+}
+
+#[test]
+fn temp() {
+    let zones = fixture("system").temp().unwrap();
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].name, "x86_pkg_temp");
+    assert_eq!(zones[0].celsius, 45.0);
+}
+
+#[test]
+fn device_from_devicetree() {
+    assert_eq!(
+        fixture("system").device().unwrap(),
+        "Raspberry Pi 4 Model B Rev 1.1"
+    );
+}
+
+#[test]
+fn gpu() {
+    assert_eq!(
+        fixture("system").gpu().unwrap(),
+        ["GA104 [GeForce RTX 3070]"]
+    );
+}
@@ -0,0 +1,23 @@
+mod common;
+
+use common::fixture;
+
+#[test]
+fn loadavg() {
+    let load = fixture("load").load().unwrap();
+    assert_eq!((load.one, load.five, load.fifteen), (0.52, 0.58, 0.59));
+    assert_eq!(
+        (load.running, load.tasks, load.last_pid),
+        (2, 1079, 123_456)
+    );
+}
+
+#[test]
+fn pressure() {
+    let pressure = fixture("load").pressure().unwrap();
+    assert_eq!(pressure.cpu.some.avg10, 1.5);
+    assert_eq!(pressure.cpu.some.total, 123_456);
+    assert_eq!(pressure.memory.full.unwrap().avg60, 0.05);
+    assert_eq!(pressure.io.some.avg300, 0.5);
+    assert_eq!(pressure.io.full.unwrap().total, 555_555);
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{ByteFormat, ByteUnits, MemInfo, MemorySize};
+
+#[test]
+fn meminfo() {
+    let meminfo = fixture("meminfo").meminfo().unwrap();
+    assert_eq!(meminfo.mem_total, 16_110_180 * 1024);
+    assert_eq!(meminfo.mem_available, Some(9_623_364 * 1024));
+    assert_eq!(meminfo.used(), (16_110_180 - 9_623_364) * 1024);
+    assert_eq!(
+        meminfo.used_htop(),
+        (16_110_180 - 2_188_956 - 412_736 - 7_251_092) * 1024
+    );
+    assert_eq!(meminfo.swap_used(), (8_388_604 - 8_126_460) * 1024);
+    // Huge page counts have no unit.
+    assert_eq!(meminfo.huge_pages_total, 2);
+    assert_eq!(meminfo.hugepagesize, 2048 * 1024);
+    assert_eq!(meminfo.other["DirectMap4k"], 532_316 * 1024);
+}
+
+#[test]
+fn meminfo_without_available() {
+    let meminfo = MemInfo::parse(
+        "MemTotal: 1000 kB\nMemFree: 100 kB\nBuffers: 50 kB\nCached: 250 kB\n",
+        "meminfo",
+    )
+    .unwrap();
+    assert_eq!(meminfo.mem_available, None);
+    assert_eq!(meminfo.available(), 400 * 1024);
+}
+
+#[test]
+fn memory_getters() {
+    let probe = fixture("meminfo");
+    assert_eq!(probe.memory_total().unwrap(), MemorySize(16_110_180 * 1024));
+    assert_eq!(probe.swap_free().unwrap(), MemorySize(8_126_460 * 1024));
+    assert_eq!(probe.memory_total().unwrap().to_string(), "15.4 GiB");
+    assert_eq!(format!("{:.2}", probe.memory_total().unwrap()), "15.36 GiB");
+}
+
+#[test]
+fn byte_format() {
+    let iec = ByteFormat::new();
+    assert_eq!(iec.format(0), "0 B");
+    assert_eq!(iec.format(1023), "1023 B");
+    assert_eq!(iec.format(1024), "1.0 KiB");
+    assert_eq!(iec.format(536_870_912), "512.0 MiB");
+
+    let si = ByteFormat::new().units(ByteUnits::Si).decimals(2);
+    assert_eq!(si.format(999), "999 B");
+    assert_eq!(si.format(1000), "1.00 kB");
+    assert_eq!(si.format(16_496_934_912), "16.50 GB");
+}
+
+#[test]
+fn byte_format_rounds_before_picking_the_unit() {
+    // Just under a unit, where rounding reaches the next one.
+    assert_eq!(ByteFormat::new().format(1_048_575), "1.0 MiB");
+    assert_eq!(ByteFormat::new().format((1 << 30) - 1), "1.0 GiB");
+    assert_eq!(ByteFormat::new().decimals(0).format(1_048_064), "1 MiB");
+    // But not when the decimals still tell them apart.
+    assert_eq!(
+        ByteFormat::new().decimals(3).format(1_048_064),
+        "1023.500 KiB"
+    );
+    let si = ByteFormat::new().units(ByteUnits::Si);
+    assert_eq!(si.format(999_999), "1.0 MB");
+    assert_eq!(si.format(999_949), "999.9 kB");
+}
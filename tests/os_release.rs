@@ -0,0 +1,37 @@
+mod common;
+
+use common::fixture;
+use nixinfo::DistroSource;
+use std::path::PathBuf;
+
+#[test]
+fn os_release_quoting() {
+    let os_release = fixture("os-release-quoting").os_release().unwrap();
+    assert_eq!(os_release.name, "Fedora Linux");
+    assert_eq!(os_release.id, "fedora");
+    assert_eq!(os_release.id_like, ["rhel", "centos"]);
+    assert_eq!(os_release.pretty_name, "Fedora \"Linux\" 39 $HOME");
+    assert_eq!(
+        os_release.version.as_deref(),
+        Some("39 (Workstation Edition)")
+    );
+    assert_eq!(os_release.variant.as_deref(), Some("Workstation Edition"));
+    assert_eq!(
+        os_release.home_url.as_deref(),
+        Some("https://fedoraproject.org/")
+    );
+    assert_eq!(os_release.ansi_color.as_deref(), Some("0;38;2;60;110;180"));
+    assert_eq!(os_release.extra["VARIANT_ID"], "workstation");
+}
+
+#[test]
+fn os_release_skips_broken_file() {
+    let probe = fixture("os-release-fallback");
+    let os_release = probe.os_release().unwrap();
+    assert_eq!(os_release.name, "Debian GNU/Linux");
+    assert_eq!(os_release.version_codename.as_deref(), Some("bookworm"));
+    assert_eq!(
+        probe.distro_info().unwrap().source,
+        DistroSource::OsRelease(PathBuf::from("/usr/lib/os-release"))
+    );
+}
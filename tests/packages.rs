@@ -0,0 +1,16 @@
+mod common;
+
+use common::fixture;
+use nixinfo::Error;
+
+#[test]
+fn commands_are_not_run_for_other_roots() {
+    let probe = fixture("system");
+    for manager in &["apk", "dnf", "eopkg", "flatpak", "pip", "rpm", "xbps"] {
+        match probe.packages(manager) {
+            Err(Error::Unsupported(_)) => {}
+            other => panic!("expected Unsupported for {}, got {:?}", manager, other),
+        }
+    }
+    assert!(probe.snapshot().packages.is_empty());
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use common::fixture;
+use nixinfo::Platform;
+
+#[test]
+fn container() {
+    let probe = fixture("container-docker");
+    assert_eq!(probe.platform(), Platform::Container);
+    // The runtime sets the hostname, /etc/hostname is left from building the image.
+    assert_eq!(probe.hostname().unwrap(), "3f2a9c1d7b6e");
+    assert_eq!(probe.device().unwrap(), "Docker container");
+
+    let probe = fixture("container-lxc");
+    assert_eq!(probe.platform(), Platform::Container);
+    assert_eq!(probe.device().unwrap(), "LXC container");
+
+    let probe = fixture("container-k8s");
+    assert_eq!(probe.platform(), Platform::Container);
+    assert_eq!(probe.device().unwrap(), "Kubernetes container");
+}
+
+#[test]
+fn wsl() {
+    let probe = fixture("wsl");
+    assert_eq!(probe.platform(), Platform::Wsl);
+    assert_eq!(probe.hostname().unwrap(), "DESKTOP-7Q2LJ4K");
+    assert_eq!(probe.device().unwrap(), "Windows Subsystem for Linux");
+}
+
+#[test]
+fn linux() {
+    let probe = fixture("kernel-hostname");
+    assert_eq!(probe.platform(), Platform::Linux);
+}
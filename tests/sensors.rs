@@ -0,0 +1,57 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{Sensor, SensorKind};
+
+#[test]
+fn chips() {
+    let chips = fixture("sensors").sensors().unwrap();
+    assert_eq!(
+        chips
+            .iter()
+            .map(|chip| (chip.index, chip.name.as_str()))
+            .collect::<Vec<_>>(),
+        [(0, "k10temp"), (2, "nct6798"), (10, "power_meter")]
+    );
+
+    let k10temp = &chips[0].sensors;
+    assert_eq!(
+        k10temp[0],
+        Sensor {
+            kind: SensorKind::Temperature,
+            label: "Tctl".to_string(),
+            input: Some(45.5),
+            min: None,
+            max: Some(95.0),
+            crit: None,
+        }
+    );
+    // Without a label the input's name is used.
+    assert_eq!(k10temp[1].label, "temp3");
+    assert_eq!(k10temp[1].to_string(), "temp3: 40.125 °C");
+
+    let nct6798 = &chips[1].sensors;
+    assert_eq!(
+        nct6798
+            .iter()
+            .map(|sensor| (sensor.kind, sensor.label.as_str(), sensor.input))
+            .collect::<Vec<_>>(),
+        [
+            (SensorKind::Fan, "fan2", Some(1200.0)),
+            (SensorKind::Voltage, "Vcore", Some(1.08)),
+            (SensorKind::Current, "curr1", Some(1.5)),
+        ]
+    );
+    assert_eq!(nct6798[0].min, Some(300.0));
+}
+
+#[test]
+fn average_and_device_directory() {
+    let chips = fixture("sensors").sensors().unwrap();
+    // The files are in `device/`, and the empty `_input` falls back to `_average`.
+    let power = &chips[2].sensors;
+    assert_eq!(power.len(), 1);
+    assert_eq!(power[0].kind, SensorKind::Power);
+    assert_eq!(power[0].input, Some(12.5));
+    assert_eq!(power[0].to_string(), "power1: 12.5 W");
+}
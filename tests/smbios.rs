@@ -0,0 +1,119 @@
+mod common;
+
+use common::{fixture, scratch};
+use nixinfo::{MemoryDevice, Probe};
+use std::fs;
+
+fn put_u16(data: &mut [u8], offset: usize, value: u16) {
+    data[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+/// An SMBIOS 3.3 type 17 (Memory Device) structure with its strings.
+fn memory_device(
+    size: u16,
+    extended_size: u32,
+    memory_type: u8,
+    speed: u16,
+    extended_speed: u32,
+) -> Vec<u8> {
+    let mut data = vec![0; 0x5c];
+    data[0] = 17;
+    data[1] = 0x5c;
+    put_u16(&mut data, 0x0c, size);
+    // Locator, bank locator, manufacturer, serial number and part number.
+    data[0x10] = 1;
+    data[0x11] = 2;
+    data[0x12] = memory_type;
+    put_u16(&mut data, 0x15, speed);
+    data[0x17] = 3;
+    data[0x18] = 4;
+    data[0x1a] = 5;
+    put_u32(&mut data, 0x1c, extended_size);
+    put_u16(&mut data, 0x20, speed);
+    put_u32(&mut data, 0x54, extended_speed);
+    put_u32(&mut data, 0x58, extended_speed);
+    for string in &[
+        "DIMM_A1",
+        "BANK 0",
+        "G.Skill",
+        "00000000",
+        "F4-3200C16-16GVK  ",
+    ] {
+        data.extend_from_slice(string.as_bytes());
+        data.push(0);
+    }
+    data.push(0);
+    data
+}
+
+/// A table with a 16 GiB DDR4 module, an empty slot, a 64 GiB DDR5 module that needs the extended
+/// size and speed fields, and the end-of-table structure, followed by bytes past the table length
+/// that must be ignored.
+fn dmi() -> Probe {
+    let root = scratch("smbios");
+    let mut table = Vec::new();
+    // A type 0 (BIOS) structure without strings, which ends with two NULs.
+    table.extend_from_slice(&[0, 4, 0, 0, 0, 0]);
+    table.extend(memory_device(16 * 1024, 0, 0x1a, 3200, 0));
+    table.extend(memory_device(0, 0, 0x02, 0, 0));
+    table.extend(memory_device(0x7fff, 64 * 1024, 0x22, 0xffff, 8000));
+    table.extend_from_slice(&[127, 4, 0, 0, 0, 0]);
+    let length = table.len();
+    table.extend(memory_device(8 * 1024, 0, 0x1a, 2666, 0));
+
+    let mut entry_point = vec![0; 24];
+    entry_point[..5].copy_from_slice(b"_SM3_");
+    put_u32(&mut entry_point, 0x0c, length as u32);
+
+    let dir = root.join("sys/firmware/dmi/tables");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("DMI"), table).unwrap();
+    fs::write(dir.join("smbios_entry_point"), entry_point).unwrap();
+    Probe::with_root(root)
+}
+
+#[test]
+fn memory_devices() {
+    let devices = dmi().memory_devices().unwrap();
+    let module = |size: u64, memory_type: &str, speed: u32| MemoryDevice {
+        locator: Some("DIMM_A1".to_string()),
+        bank_locator: Some("BANK 0".to_string()),
+        size: Some(size * 1024 * 1024 * 1024),
+        memory_type: Some(memory_type.to_string()),
+        speed: Some(speed),
+        configured_speed: Some(speed),
+        manufacturer: Some("G.Skill".to_string()),
+        part_number: Some("F4-3200C16-16GVK".to_string()),
+        serial_number: Some("00000000".to_string()),
+    };
+    assert_eq!(
+        devices,
+        [module(16, "DDR4", 3200), module(64, "DDR5", 8000)]
+    );
+}
+
+/// The tables of a QEMU q35 guest with 20 GiB of RAM, written out by hand in the layout QEMU's
+/// `hw/smbios` generates rather than dumped from a machine: an SMBIOS 3.0 entry point, and a
+/// 0x28 byte type 17 for each DIMM of at most 16 GiB with only a locator and manufacturer.
+#[test]
+fn qemu() {
+    let dimm = |locator: &str, gib: u64| MemoryDevice {
+        locator: Some(locator.to_string()),
+        bank_locator: None,
+        size: Some(gib * 1024 * 1024 * 1024),
+        memory_type: Some("RAM".to_string()),
+        speed: None,
+        configured_speed: None,
+        manufacturer: Some("QEMU".to_string()),
+        part_number: None,
+        serial_number: None,
+    };
+    assert_eq!(
+        fixture("smbios-qemu").memory_devices().unwrap(),
+        [dimm("DIMM 0", 16), dimm("DIMM 1", 4)]
+    );
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{SwapDevice, Zswap};
+
+#[test]
+fn swaps() {
+    let swaps = fixture("swap").swaps().unwrap();
+    assert_eq!(
+        swaps,
+        [
+            SwapDevice {
+                path: "/dev/nvme0n1p3".to_string(),
+                kind: "partition".to_string(),
+                size: 8_388_604 * 1024,
+                used: 262_144 * 1024,
+                priority: -2,
+            },
+            // The space is escaped as \040.
+            SwapDevice {
+                path: "/swap file".to_string(),
+                kind: "file".to_string(),
+                size: 1_048_572 * 1024,
+                used: 0,
+                priority: 10,
+            },
+        ]
+    );
+}
+
+#[test]
+fn zram() {
+    let devices = fixture("swap").zram().unwrap();
+    assert_eq!(
+        devices
+            .iter()
+            .map(|device| device.name.as_str())
+            .collect::<Vec<_>>(),
+        ["zram0", "zram2", "zram10"]
+    );
+    let zram0 = &devices[0];
+    assert_eq!(zram0.disksize, 8 << 30);
+    assert_eq!(zram0.algorithm.as_deref(), Some("zstd"));
+    assert_eq!(zram0.orig_data_size, 1 << 30);
+    assert_eq!(zram0.compr_data_size, 256 << 20);
+    assert_eq!(zram0.mem_used_total, 270 << 20);
+    assert_eq!(zram0.ratio(), Some(4.0));
+    // An unconfigured device has nothing to report.
+    assert_eq!(devices[1].algorithm, None);
+    assert_eq!(devices[1].ratio(), None);
+    // Kernels before 4.11 have a file for each statistic instead of `mm_stat`.
+    assert_eq!(devices[2].algorithm.as_deref(), Some("lzo"));
+    assert_eq!(devices[2].mem_used_total, 2_162_688);
+    assert_eq!(devices[2].ratio(), Some(2.0));
+}
+
+#[test]
+fn zswap() {
+    assert_eq!(
+        fixture("swap").zswap().unwrap(),
+        Zswap {
+            enabled: true,
+            compressor: Some("zstd".to_string()),
+            zpool: None,
+            max_pool_percent: Some(20),
+            accept_threshold_percent: Some(90),
+            shrinker_enabled: Some(false),
+        }
+    );
+    assert!(fixture("meminfo").zswap().is_err());
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{CoolingDevice, TemperatureUnit, TripPoint, TripPointKind};
+
+#[test]
+fn zones() {
+    let zones = fixture("thermal").temp().unwrap();
+    // The zones whose temperature can't be read are skipped.
+    assert_eq!(zones.len(), 1);
+    let zone = &zones[0];
+    assert_eq!(zone.name, "acpitz");
+    assert_eq!(zone.celsius, 45.0);
+    assert_eq!(zone.policy.as_deref(), Some("step_wise"));
+    assert_eq!(zone.mode.as_deref(), Some("enabled"));
+    // Trip points of an unknown type are left out.
+    assert_eq!(
+        zone.trip_points,
+        [
+            TripPoint {
+                kind: TripPointKind::Critical,
+                celsius: 105.0,
+            },
+            TripPoint {
+                kind: TripPointKind::Passive,
+                celsius: 95.0,
+            },
+        ]
+    );
+    assert_eq!(
+        zone.cooling_devices,
+        [
+            CoolingDevice {
+                name: "Processor".to_string(),
+                cur_state: 0,
+                max_state: 3,
+            },
+            CoolingDevice {
+                name: "Fan".to_string(),
+                cur_state: 1,
+                max_state: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn units() {
+    let zone = &fixture("thermal").temp().unwrap()[0];
+    assert_eq!(zone.fahrenheit(), 113.0);
+    assert_eq!(zone.kelvin(), 318.15);
+    assert_eq!(zone.format(TemperatureUnit::Fahrenheit), "acpitz: 113.0°F");
+    assert_eq!(TemperatureUnit::Kelvin.format(-273.15), "0.0K");
+}
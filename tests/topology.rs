@@ -0,0 +1,46 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{Cache, CoreKind};
+
+/// Two P-cores with SMT, two E-cores sharing an L2 and one offline CPU.
+#[test]
+fn hybrid() {
+    let topology = fixture("topology").cpu_topology().unwrap();
+    assert_eq!(topology.online, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(topology.offline, [6]);
+    assert_eq!(topology.packages, 1);
+    assert_eq!(topology.cores_per_package, 4);
+    assert_eq!(topology.threads_per_core, 2);
+    let kinds: Vec<_> = topology.cpus.iter().map(|cpu| cpu.kind).collect();
+    assert_eq!(kinds[..4], [Some(CoreKind::Performance); 4]);
+    assert_eq!(kinds[4..], [Some(CoreKind::Efficiency); 2]);
+    assert_eq!(topology.cpus[3].core, Some(4));
+    assert!(topology.cpus.iter().all(|cpu| cpu.node == Some(0)));
+    assert_eq!(topology.numa_nodes.len(), 1);
+}
+
+#[test]
+fn shared_caches() {
+    let topology = fixture("topology").cpu_topology().unwrap();
+    // Each cache is listed once, not once for every CPU sharing it.
+    assert_eq!(topology.caches.len(), 12);
+    assert!(topology.caches.contains(&Cache {
+        level: 2,
+        kind: "Unified".to_string(),
+        size: 2048 * 1024,
+        shared_cpus: vec![4, 5],
+    }));
+    assert_eq!(
+        topology.caches.last().unwrap(),
+        &Cache {
+            level: 3,
+            kind: "Unified".to_string(),
+            size: 20 * 1024 * 1024,
+            shared_cpus: vec![0, 1, 2, 3, 4, 5],
+        }
+    );
+    assert_eq!(topology.cache_size(1), 352 * 1024);
+    assert_eq!(topology.cache_size(2), 4608 * 1024);
+    assert_eq!(topology.cache_size(3), 20 * 1024 * 1024);
+}
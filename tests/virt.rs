@@ -0,0 +1,55 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{Virt, VirtKind};
+
+fn kind(name: &str) -> Option<VirtKind> {
+    fixture(name).virtualization().map(|virt| virt.kind)
+}
+
+#[test]
+fn qemu() {
+    // QEMU is the DMI vendor with and without KVM, kvm-clock tells them apart.
+    assert_eq!(
+        fixture("virt-kvm").virtualization(),
+        Some(Virt {
+            kind: VirtKind::Kvm,
+            vendor: Some("QEMU".to_string()),
+        })
+    );
+    assert_eq!(kind("virt-qemu"), Some(VirtKind::Qemu));
+}
+
+#[test]
+fn dmi() {
+    assert_eq!(kind("virt-hyperv"), Some(VirtKind::HyperV));
+    // EC2 bare metal instances have the same vendor as virtual ones.
+    assert_eq!(kind("virt-ec2-metal"), None);
+    assert_eq!(kind("virt-bare-metal"), None);
+}
+
+#[test]
+fn xen() {
+    assert_eq!(kind("virt-xen-domu"), Some(VirtKind::Xen));
+    // dom0 manages the hypervisor, even though it has a Xen clocksource.
+    assert_eq!(kind("virt-xen-dom0"), None);
+}
+
+#[test]
+fn devicetree() {
+    assert_eq!(kind("virt-arm"), Some(VirtKind::Kvm));
+}
+
+#[test]
+fn hypervisor_flag() {
+    // Without DMI or a clocksource, the CPU flag only says there is a hypervisor.
+    assert_eq!(
+        fixture("cpu-x86").virtualization(),
+        Some(Virt {
+            kind: VirtKind::Other,
+            vendor: None,
+        })
+    );
+    assert_eq!(VirtKind::Other.to_string(), "Other");
+    assert_eq!(VirtKind::HyperV.to_string(), "Hyper-V");
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::fixture;
+use nixinfo::{SmtControl, Vulnerability, VulnerabilityStatus};
+
+fn find<'a>(vulnerabilities: &'a [Vulnerability], name: &str) -> &'a Vulnerability {
+    vulnerabilities
+        .iter()
+        .find(|vulnerability| vulnerability.name == name)
+        .unwrap()
+}
+
+#[test]
+fn statuses() {
+    let vulnerabilities = fixture("vulnerabilities").cpu_vulnerabilities().unwrap();
+    assert_eq!(vulnerabilities.len(), 6);
+
+    let meltdown = find(&vulnerabilities, "meltdown");
+    assert_eq!(meltdown.status, VulnerabilityStatus::NotAffected);
+    assert_eq!(meltdown.detail, None);
+
+    let spectre_v1 = find(&vulnerabilities, "spectre_v1");
+    assert_eq!(spectre_v1.status, VulnerabilityStatus::Mitigated);
+    assert_eq!(
+        spectre_v1.detail.as_deref(),
+        Some("usercopy/swapgs barriers and __user pointer sanitization")
+    );
+
+    let mds = find(&vulnerabilities, "mds");
+    assert_eq!(mds.status, VulnerabilityStatus::Vulnerable);
+    assert_eq!(
+        mds.detail.as_deref(),
+        Some("Clear CPU buffers attempted, no microcode; SMT vulnerable")
+    );
+
+    let itlb_multihit = find(&vulnerabilities, "itlb_multihit");
+    assert_eq!(itlb_multihit.status, VulnerabilityStatus::Mitigated);
+    assert_eq!(itlb_multihit.detail.as_deref(), Some("KVM: VMX disabled"));
+
+    let l1tf = find(&vulnerabilities, "l1tf");
+    assert_eq!(l1tf.status, VulnerabilityStatus::Vulnerable);
+    assert_eq!(l1tf.detail, None);
+
+    let gather_data_sampling = find(&vulnerabilities, "gather_data_sampling");
+    assert_eq!(gather_data_sampling.status, VulnerabilityStatus::Unknown);
+}
+
+#[test]
+fn smt() {
+    let smt = fixture("vulnerabilities").smt().unwrap();
+    assert_eq!(smt.control, SmtControl::On);
+    assert_eq!(smt.active, Some(true));
+}